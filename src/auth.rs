@@ -0,0 +1,345 @@
+use std::{collections::HashMap, io, process::Output, time::Duration};
+
+use common_macros::hash_map;
+use rand::{distributions::Alphanumeric, Rng};
+use reqwest::{Client, Url};
+use serde::Deserialize;
+use thiserror::Error;
+#[cfg(not(feature = "nightly"))]
+use tokio::io::{self as tio, AsyncBufReadExt, BufReader};
+
+use crate::{
+    credentials::Credentials,
+    error::{self, Result},
+    scope::ScopeSet,
+};
+
+pub(crate) const O_AUTH_ENDPOINT: &str = "https://accounts.spotify.com/authorize";
+/// The port the redirect URI points at, unless configured otherwise.
+pub(crate) const REDIRECT_PORT: u16 = 8888;
+/// The length of the random `state` sent to `/authorize`.
+const STATE_LENGTH: usize = 32;
+/// How long the user has to authorize access before the callback server gives up.
+const CALLBACK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+pub(crate) const ACCOUNTS_SERVICE: &str = "https://accounts.spotify.com/api/token";
+
+mod callback;
+mod pkce;
+
+#[cfg(not(feature = "nightly"))]
+use callback::{Callback, CallbackServer};
+pub(crate) use pkce::Pkce;
+
+// `try` blocks don't even parse on stable, so the nightly flavour lives in its own file
+#[cfg(feature = "nightly")]
+mod nightly;
+#[cfg(feature = "nightly")]
+pub(crate) use nightly::authorize_scope;
+
+pub(crate) async fn refresh_tokens(
+    requester: &Client,
+    token_endpoint: &str,
+    credentials: &Credentials,
+    refresh_token: &str,
+) -> Result<Tokens> {
+    acquire_tokens(
+        requester,
+        token_endpoint,
+        &Data {
+            client_id: credentials.client_id(),
+            client_secret: credentials.client_secret(),
+            prior_data: Refresh { refresh_token },
+        },
+    )
+    .await
+}
+
+pub(crate) async fn get_tokens(
+    requester: &Client,
+    token_endpoint: &str,
+    credentials: &Credentials,
+    authorization: &Authorization,
+    code_verifier: Option<&str>,
+) -> Result<Tokens> {
+    acquire_tokens(
+        requester,
+        token_endpoint,
+        &Data {
+            client_id: credentials.client_id(),
+            client_secret: credentials.client_secret(),
+            prior_data: Initial {
+                redirect_uri: &authorization.redirect_uri,
+                code: &authorization.code,
+                code_verifier,
+            },
+        },
+    )
+    .await
+}
+
+/// Tokens for the application itself, which can only access public data.
+pub(crate) async fn get_app_tokens(
+    requester: &Client,
+    token_endpoint: &str,
+    credentials: &Credentials,
+) -> Result<Tokens> {
+    acquire_tokens(
+        requester,
+        token_endpoint,
+        &Data {
+            client_id: credentials.client_id(),
+            client_secret: Some(credentials.require_secret()?),
+            prior_data: ClientCredentials,
+        },
+    )
+    .await
+}
+
+async fn acquire_tokens(
+    requester: &Client,
+    token_endpoint: &str,
+    prior_data: &Data<'_, '_, '_>,
+) -> Result<Tokens> {
+    let response = requester
+        .post(token_endpoint)
+        .form(&HashMap::from(prior_data))
+        .send()
+        .await?;
+    error::json(response).await
+}
+
+struct Data<'prior, 'id, 'secret> {
+    client_id: &'id str,
+    /// Not sent when authorizing with PKCE.
+    client_secret: Option<&'secret str>,
+    prior_data: PriorData<'prior>,
+}
+
+enum PriorData<'prior> {
+    Initial {
+        code: &'prior str,
+        redirect_uri: &'prior str,
+        code_verifier: Option<&'prior str>,
+    },
+    Refresh {
+        refresh_token: &'prior str,
+    },
+    ClientCredentials,
+}
+
+use PriorData::{ClientCredentials, Initial, Refresh};
+
+impl<'prior, 'id, 'secret> From<&Data<'prior, 'id, 'secret>> for HashMap<&'static str, &'prior str>
+where
+    'id: 'prior,
+    'secret: 'prior,
+{
+    fn from(data: &Data<'prior, 'id, 'secret>) -> Self {
+        let mut form = match data.prior_data {
+            PriorData::Initial {
+                code,
+                redirect_uri,
+                code_verifier,
+            } => {
+                let mut form = hash_map! {
+                    "grant_type" => "authorization_code",
+                    "code" => code,
+                    "redirect_uri" => redirect_uri,
+                };
+                if let Some(code_verifier) = code_verifier {
+                    form.insert("code_verifier", code_verifier);
+                }
+                form
+            }
+            PriorData::Refresh { refresh_token } => hash_map! {
+                "grant_type" => "refresh_token",
+                "refresh_token" => refresh_token,
+            },
+            PriorData::ClientCredentials => hash_map! {
+                "grant_type" => "client_credentials",
+            },
+        };
+        form.insert("client_id", data.client_id);
+        if let Some(client_secret) = data.client_secret {
+            form.insert("client_secret", client_secret);
+        }
+        form
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Tokens {
+    /// An access token that can be provided in subsequent calls, for example to Spotify Web API services.
+    pub(crate) access_token: String,
+    /// How the access token may be used: always “Bearer”.
+    #[allow(dead_code)]
+    pub(crate) token_type: String,
+    /// A space-separated list of scopes which have been granted for this `access_token`.
+    /// Missing for application tokens, which are granted none.
+    #[serde(default)]
+    pub(crate) scope: ScopeSet,
+    /// The time period (in seconds) for which the access token is valid.
+    pub(crate) expires_in: usize,
+    /// A token that can be sent to the Spotify Accounts service in place of an authorization code.
+    /// When the access code expires, send a POST request to the Accounts service `/api/token` endpoint, but use this code in place of an authorization code.
+    /// A new access token will be returned. A new refresh token might be returned too.
+    /// Application tokens don't have one.
+    pub(crate) refresh_token: Option<String>,
+}
+
+/// Why the user's authorization couldn't be obtained.
+#[derive(Debug, Error)]
+pub enum AuthorizationError {
+    /// The user declined to authorize access.
+    #[error("You denied access")]
+    AccessDenied,
+    /// Authorization failed for any other reason.
+    #[error("Authorization was rejected: {0}")]
+    Rejected(String),
+    /// The redirect had neither a code nor an error.
+    #[error("The redirect didn't contain an authorization code")]
+    MissingCode,
+    /// The user didn't authorize access in time.
+    #[error("Gave up waiting for authorization after {0:?}")]
+    Timeout(Duration),
+    /// The URL the user pasted isn't a URL.
+    #[error("{0:?} is not the URL you were redirected to")]
+    InvalidRedirect(String),
+    /// The redirect wasn't a response to our request, and might have been forged.
+    #[error("The state of the redirect doesn't match the one sent with the request")]
+    StateMismatch {
+        expected: String,
+        received: Option<String>,
+    },
+}
+
+/// A random value to send as the `state`, so the redirect can be matched with the request, protecting against CSRF.
+fn new_state() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(STATE_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// A code the user authorized, to be exchanged for tokens.
+#[derive(Debug)]
+pub(crate) struct Authorization {
+    pub(crate) code: String,
+    /// The redirect URI that was used to get the code, which must be sent again with it.
+    pub(crate) redirect_uri: String,
+}
+
+/// The user is asked to authorize access.
+/// The user is redirected to a callback server on `redirect_port`, `0` meaning any free port.
+/// If it can't be started, the user is asked to paste the URL they were redirected to.
+#[cfg(not(feature = "nightly"))]
+pub(crate) async fn authorize_scope(
+    requester: &Client,
+    authorize_endpoint: &str,
+    client_id: &str,
+    scopes: &ScopeSet,
+    pkce: Option<&Pkce>,
+    redirect_port: u16,
+) -> Result<Authorization> {
+    let callback_server = CallbackServer::bind(redirect_port).await;
+    let redirect_uri = match &callback_server {
+        Ok(callback_server) => callback_server.redirect_uri().to_owned(),
+        Err(_) => callback::redirect_uri(redirect_port),
+    };
+
+    let state = new_state();
+
+    ask_for_authorization(
+        requester,
+        authorize_endpoint,
+        client_id,
+        &redirect_uri,
+        &state,
+        scopes,
+        pkce,
+    )
+    .await?;
+
+    let code = match callback_server {
+        Ok(callback_server) => callback_server
+            .receive(&state, CALLBACK_TIMEOUT)
+            .await?
+            .into_code(&state)?,
+        Err(_) => {
+            let mut line = String::new();
+            {
+                async fn __try(line: &mut String) -> io::Result<()> {
+                    println!("Please enter the URL you were redirected to:");
+                    BufReader::new(tio::stdin()).read_line(line).await?;
+                    Ok(())
+                }
+                __try(&mut line).await
+            }?;
+            Callback::from_pasted(&line)?.into_code(&state)?
+        }
+    };
+
+    Ok(Authorization { code, redirect_uri })
+}
+
+/// Request authorization; the user logs in and authorizes access.
+/// I send a request to the Spotify Accounts service.
+/// The user is asked to authorize access within the scopes.
+/// - If the user is not logged in, they are prompted to do so using their Spotify credentials.
+/// - When the user is logged in, they are asked to authorize access to the data sets defined in the scopes.
+///
+/// The `state` comes back with the redirect, proving it is a response to this very request.
+/// With PKCE, the challenge is sent along so the code can later be exchanged without the client secret.
+async fn ask_for_authorization(
+    requester: &Client,
+    authorize_endpoint: &str,
+    client_id: &str,
+    redirect_uri: &str,
+    state: &str,
+    scopes: &ScopeSet,
+    pkce: Option<&Pkce>,
+) -> Result<()> {
+    let scopes = scopes.to_string();
+    let mut query = hash_map! {
+        "response_type" => "code",
+        "client_id" => client_id,
+        "redirect_uri" => redirect_uri,
+        "state" => state,
+        "scope" => &*scopes,
+    };
+    if let Some(pkce) = pkce {
+        query.insert("code_challenge", &pkce.challenge);
+        query.insert("code_challenge_method", "S256");
+    }
+    let response = requester
+        .get(authorize_endpoint)
+        .query(&query)
+        .send()
+        .await?;
+    if let Err((url, _io_error)) = error::error_for_status(response)
+        .await?
+        .url()
+        .open_in_browser()
+    {
+        warn!("Couldn't open browser, please head to {}", url);
+    }
+    Ok(())
+}
+
+/// Somthing that can be opened in a browser.
+trait Openable {
+    /// The string representation of the URL
+    fn url(&self) -> &str;
+    /// Opens the url in default web browser
+    fn open_in_browser(&self) -> Result<Output, (&Self, io::Error)> {
+        webbrowser::open(self.url()).map_err(|e| (self, e))
+    }
+}
+
+impl Openable for Url {
+    fn url(&self) -> &str {
+        self.as_str()
+    }
+}
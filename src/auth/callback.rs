@@ -0,0 +1,334 @@
+use std::{io, net::Ipv4Addr, time::Duration};
+
+use reqwest::Url;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    time,
+};
+
+use super::AuthorizationError;
+use crate::error::Result;
+
+/// The path the user is redirected to, which has to be part of a redirect URI whitelisted in the dashboard.
+const CALLBACK_PATH: &str = "/auth/callback/spotify";
+
+/// How long a connection gets to send its request, so one that never does can't hold up the redirect,
+/// as connections are handled one at a time.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Requests bigger than this aren't ours, the redirect is just a request line and a few headers.
+const MAX_REQUEST_LENGTH: u64 = 16 * 1024;
+
+const SUCCESS_PAGE: &str = "<!DOCTYPE html>
+<html>
+<head><title>spotifry</title></head>
+<body><h1>All set!</h1><p>You can close this tab and head back to the terminal.</p></body>
+</html>
+";
+
+const FAILURE_PAGE: &str = "<!DOCTYPE html>
+<html>
+<head><title>spotifry</title></head>
+<body><h1>Authorization failed</h1><p>Head back to the terminal for the details.</p></body>
+</html>
+";
+
+/// The redirect URI for a callback server on `port`.
+pub(crate) fn redirect_uri(port: u16) -> String {
+    format!("http://127.0.0.1:{}{}", port, CALLBACK_PATH)
+}
+
+/// What the Accounts service redirected the user back with.
+#[derive(Debug, Default)]
+pub(crate) struct Callback {
+    /// An authorization code that can be exchanged for an access token.
+    pub(crate) code: Option<String>,
+    /// The reason authorization failed, for example: “access_denied”.
+    pub(crate) error: Option<String>,
+    /// The value of the `state` parameter supplied in the request.
+    pub(crate) state: Option<String>,
+}
+
+impl Callback {
+    /// Picks the query parameters out of the URL the user was redirected to.
+    pub(crate) fn from_url(url: &Url) -> Self {
+        url.query_pairs()
+            .fold(Self::default(), |mut callback, (key, value)| {
+                match &*key {
+                    "code" => callback.code = Some(value.into_owned()),
+                    "error" => callback.error = Some(value.into_owned()),
+                    "state" => callback.state = Some(value.into_owned()),
+                    _ => {}
+                }
+                callback
+            })
+    }
+
+    /// Parses a URL the user pasted, trimming the line break and any whitespace they copied along.
+    pub(crate) fn from_pasted(line: &str) -> Result<Self, AuthorizationError> {
+        let url = Url::parse(line.trim())
+            .map_err(|_| AuthorizationError::InvalidRedirect(line.trim().to_owned()))?;
+        Ok(Self::from_url(&url))
+    }
+
+    /// Whether this is the redirect for the request sent with `state`.
+    pub(crate) fn is_for(&self, state: &str) -> bool {
+        self.state.as_deref() == Some(state)
+    }
+
+    /// The authorization code, unless authorization failed or the redirect isn't for the request sent with `state`.
+    pub(crate) fn into_code(self, state: &str) -> Result<String, AuthorizationError> {
+        if !self.is_for(state) {
+            return Err(AuthorizationError::StateMismatch {
+                expected: state.to_owned(),
+                received: self.state,
+            });
+        }
+        match (self.code, self.error) {
+            (_, Some(error)) if error == "access_denied" => Err(AuthorizationError::AccessDenied),
+            (_, Some(error)) => Err(AuthorizationError::Rejected(error)),
+            (Some(code), None) => Ok(code),
+            (None, None) => Err(AuthorizationError::MissingCode),
+        }
+    }
+}
+
+/// A tiny HTTP server which waits for the user to be redirected back to it after authorizing.
+#[derive(Debug)]
+pub(crate) struct CallbackServer {
+    listener: TcpListener,
+    redirect_uri: String,
+}
+
+impl CallbackServer {
+    /// Listens on `port` on the loopback interface, or on any free port if it's `0`.
+    pub(crate) async fn bind(port: u16) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port)).await?;
+        let redirect_uri = redirect_uri(listener.local_addr()?.port());
+        Ok(Self {
+            listener,
+            redirect_uri,
+        })
+    }
+
+    /// The redirect URI to send to `/authorize`.
+    pub(crate) fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// Waits for the redirect for the authorization request sent with `state`, giving up after `timeout`.
+    /// Other requests the browser might make, like for `/favicon.ico`, are answered with a 404 and ignored,
+    /// and so are callbacks with another state, which can't be for this request, with a 400.
+    pub(crate) async fn receive(self, state: &str, timeout: Duration) -> Result<Callback> {
+        time::timeout(timeout, async {
+            loop {
+                let (mut stream, _sender) = self.listener.accept().await?;
+                // a single misbehaving connection shouldn't fail the whole authorization
+                let target = match time::timeout(READ_TIMEOUT, read_target(&mut stream)).await {
+                    Ok(target) => target,
+                    // e.g. a preconnect from the browser, which may never send anything
+                    Err(_) => continue,
+                };
+                match target {
+                    Ok(Some(url)) if url.path() == CALLBACK_PATH => {
+                        let callback = Callback::from_url(&url);
+                        if !callback.is_for(state) {
+                            let _ = respond(&mut stream, "400 Bad Request", FAILURE_PAGE).await;
+                            continue;
+                        }
+                        let page = match callback.error {
+                            None => SUCCESS_PAGE,
+                            Some(_) => FAILURE_PAGE,
+                        };
+                        let _ = respond(&mut stream, "200 OK", page).await;
+                        break Ok(callback);
+                    }
+                    Ok(_) => {
+                        let _ = respond(&mut stream, "404 Not Found", "").await;
+                    }
+                    Err(_) => {
+                        let _ = respond(&mut stream, "400 Bad Request", "").await;
+                    }
+                }
+            }
+        })
+        .await
+        .map_err(|_| AuthorizationError::Timeout(timeout))?
+    }
+}
+
+/// Reads a request, returning the URL from its request line if it was a `GET`.
+async fn read_target(stream: &mut TcpStream) -> io::Result<Option<Url>> {
+    let mut reader = BufReader::new(stream).take(MAX_REQUEST_LENGTH);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    // the headers don't matter, but the browser shouldn't be reset before it's done sending them
+    let mut header = String::new();
+    while reader.read_line(&mut header).await? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some("GET"), Some(target), Some(version)) if version.starts_with("HTTP/") => {
+            Url::parse("http://127.0.0.1")
+                .and_then(|base| base.join(target))
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        }
+        (Some(_), Some(_), Some(_)) => Ok(None),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Malformed request line",
+        )),
+    }
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) -> io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sends `request` to a fresh connection and reads it back with `read_target`.
+    async fn read_request(request: &[u8]) -> io::Result<Option<Url>> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        client.write_all(request).await.unwrap();
+        client.shutdown().await.unwrap();
+        let (mut stream, _) = listener.accept().await.unwrap();
+        read_target(&mut stream).await
+    }
+
+    /// Sends a `GET` for `target` and returns the response.
+    async fn get(addr: std::net::SocketAddr, target: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let request = format!("GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n", target);
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn reads_the_target_of_a_get() {
+        let url = read_request(
+            b"GET /auth/callback/spotify?code=abc&state=xyz HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n",
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(url.path(), CALLBACK_PATH);
+        assert_eq!(url.query(), Some("code=abc&state=xyz"));
+    }
+
+    #[tokio::test]
+    async fn ignores_other_methods() {
+        let target =
+            read_request(b"POST /auth/callback/spotify HTTP/1.1\r\nContent-Length: 0\r\n\r\n")
+                .await
+                .unwrap();
+        assert!(target.is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_request_lines() {
+        for request in [&b"\r\n\r\n"[..], b"GET\r\n\r\n", b"GET /\r\n\r\n"] {
+            let error = read_request(request).await.unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[tokio::test]
+    async fn stops_reading_oversized_requests() {
+        let mut request = b"GET /".to_vec();
+        request.resize(MAX_REQUEST_LENGTH as usize + 1024, b'a');
+        request.extend_from_slice(b" HTTP/1.1\r\n\r\n");
+        let error = read_request(&request).await.unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn picks_the_code_out_of_the_redirect() {
+        let url = Url::parse("http://127.0.0.1/auth/callback/spotify?code=abc&state=xyz").unwrap();
+        let callback = Callback::from_url(&url);
+        assert_eq!(callback.code.as_deref(), Some("abc"));
+        assert_eq!(callback.error, None);
+        assert_eq!(callback.into_code("xyz").unwrap(), "abc");
+    }
+
+    #[test]
+    fn reports_denied_access() {
+        let url =
+            Url::parse("http://127.0.0.1/auth/callback/spotify?error=access_denied&state=xyz")
+                .unwrap();
+        assert!(matches!(
+            Callback::from_url(&url).into_code("xyz"),
+            Err(AuthorizationError::AccessDenied)
+        ));
+
+        let url = Url::parse("http://127.0.0.1/auth/callback/spotify?error=server_error&state=xyz")
+            .unwrap();
+        assert!(matches!(
+            Callback::from_url(&url).into_code("xyz"),
+            Err(AuthorizationError::Rejected(error)) if error == "server_error"
+        ));
+    }
+
+    #[test]
+    fn reports_a_missing_code() {
+        let url = Url::parse("http://127.0.0.1/auth/callback/spotify?state=xyz").unwrap();
+        assert!(matches!(
+            Callback::from_url(&url).into_code("xyz"),
+            Err(AuthorizationError::MissingCode)
+        ));
+    }
+
+    #[test]
+    fn checks_the_state_first() {
+        let url =
+            Url::parse("http://127.0.0.1/auth/callback/spotify?error=access_denied&state=abc")
+                .unwrap();
+        assert!(matches!(
+            Callback::from_url(&url).into_code("xyz"),
+            Err(AuthorizationError::StateMismatch { expected, received })
+                if expected == "xyz" && received.as_deref() == Some("abc")
+        ));
+
+        let url = Url::parse("http://127.0.0.1/auth/callback/spotify?code=abc").unwrap();
+        assert!(matches!(
+            Callback::from_url(&url).into_code("xyz"),
+            Err(AuthorizationError::StateMismatch { received: None, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn keeps_waiting_for_the_right_callback() {
+        let server = CallbackServer::bind(0).await.unwrap();
+        let addr = server.listener.local_addr().unwrap();
+        let callback =
+            tokio::spawn(async move { server.receive("xyz", Duration::from_secs(10)).await });
+
+        assert!(get(addr, "/favicon.ico").await.starts_with("HTTP/1.1 404"));
+        let forged = get(addr, "/auth/callback/spotify?code=forged&state=abc").await;
+        assert!(forged.starts_with("HTTP/1.1 400"));
+        let redirect = get(addr, "/auth/callback/spotify?code=abc&state=xyz").await;
+        assert!(redirect.starts_with("HTTP/1.1 200") && redirect.ends_with(SUCCESS_PAGE));
+
+        let callback = callback.await.unwrap().unwrap();
+        assert_eq!(callback.into_code("xyz").unwrap(), "abc");
+    }
+}
@@ -0,0 +1,60 @@
+use std::io;
+
+use reqwest::Client;
+use tokio::io::{self as tio, AsyncBufReadExt, BufReader};
+
+use super::{
+    ask_for_authorization,
+    callback::{self, Callback, CallbackServer},
+    new_state, Authorization, Pkce, CALLBACK_TIMEOUT,
+};
+use crate::{error::Result, scope::ScopeSet};
+
+/// The user is asked to authorize access.
+/// The user is redirected to a callback server on `redirect_port`, `0` meaning any free port.
+/// If it can't be started, the user is asked to paste the URL they were redirected to.
+pub(crate) async fn authorize_scope(
+    requester: &Client,
+    authorize_endpoint: &str,
+    client_id: &str,
+    scopes: &ScopeSet,
+    pkce: Option<&Pkce>,
+    redirect_port: u16,
+) -> Result<Authorization> {
+    let callback_server = CallbackServer::bind(redirect_port).await;
+    let redirect_uri = match &callback_server {
+        Ok(callback_server) => callback_server.redirect_uri().to_owned(),
+        Err(_) => callback::redirect_uri(redirect_port),
+    };
+
+    let state = new_state();
+
+    ask_for_authorization(
+        requester,
+        authorize_endpoint,
+        client_id,
+        &redirect_uri,
+        &state,
+        scopes,
+        pkce,
+    )
+    .await?;
+
+    let code = match callback_server {
+        Ok(callback_server) => callback_server
+            .receive(&state, CALLBACK_TIMEOUT)
+            .await?
+            .into_code(&state)?,
+        Err(_) => {
+            println!("Please enter the URL you were redirected to:");
+            let mut line = String::new();
+            let read: io::Result<()> = try {
+                BufReader::new(tio::stdin()).read_line(&mut line).await?;
+            };
+            read?;
+            Callback::from_pasted(&line)?.into_code(&state)?
+        }
+    };
+
+    Ok(Authorization { code, redirect_uri })
+}
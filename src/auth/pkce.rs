@@ -0,0 +1,30 @@
+use rand::{distributions::Alphanumeric, Rng};
+use sha2::{Digest, Sha256};
+
+/// The length of the code verifier, which has to be between 43 and 128 characters.
+const VERIFIER_LENGTH: usize = 128;
+
+/// The secret of a single [PKCE](https://tools.ietf.org/html/rfc7636) authorization attempt.
+/// The challenge is sent to `/authorize`, and only whoever knows the verifier can then exchange the code for tokens.
+#[derive(Debug)]
+pub(crate) struct Pkce {
+    pub(crate) verifier: String,
+    pub(crate) challenge: String,
+}
+
+impl Pkce {
+    /// A fresh verifier, and its `S256` challenge.
+    pub(crate) fn new() -> Self {
+        let verifier: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(VERIFIER_LENGTH)
+            .map(char::from)
+            .collect();
+        let challenge =
+            base64::encode_config(Sha256::digest(verifier.as_bytes()), base64::URL_SAFE_NO_PAD);
+        Self {
+            verifier,
+            challenge,
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::session::Session;
+
+/// A file the session is kept in between runs, so the user doesn't have to authorize every time.
+/// It only holds tokens of one application, from one token endpoint.
+#[derive(Debug, Clone)]
+pub(crate) struct TokenCache {
+    path: PathBuf,
+    client_id: String,
+    token_endpoint: String,
+}
+
+/// What's written to the file: the session, along with where its tokens come from.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile<T, S> {
+    /// The application the tokens were issued to, the only one that can use and refresh them.
+    #[serde(default)]
+    client_id: T,
+    /// The token endpoint that issued them.
+    #[serde(default)]
+    token_endpoint: T,
+    #[serde(flatten)]
+    session: S,
+}
+
+impl TokenCache {
+    /// The cache in `path` for tokens issued to the application with `client_id` by `token_endpoint`.
+    pub(crate) fn new(
+        path: PathBuf,
+        client_id: impl Into<String>,
+        token_endpoint: impl Into<String>,
+    ) -> Self {
+        Self {
+            path,
+            client_id: client_id.into(),
+            token_endpoint: token_endpoint.into(),
+        }
+    }
+
+    /// `spotifry/tokens.json` in the user's config directory.
+    pub(crate) fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("spotifry").join("tokens.json"))
+    }
+
+    /// The cached session, if there is one and its tokens were issued to the same application by the same endpoint.
+    pub(crate) fn load(&self) -> Result<Option<Session>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Couldn't read the token cache {}", self.path.display())
+                })
+            }
+        };
+        let cached: CacheFile<String, Session> = serde_json::from_str(&contents)
+            .with_context(|| format!("The token cache {} is corrupted", self.path.display()))?;
+        // e.g. cached by a version which didn't record these, or for a fake Accounts service
+        if cached.client_id != self.client_id || cached.token_endpoint != self.token_endpoint {
+            return Ok(None);
+        }
+        Ok(Some(cached.session))
+    }
+
+    /// Overwrites the cache with `session`, readable only by the current user.
+    pub(crate) fn save(&self, session: &Session) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Couldn't create {}", dir.display()))?;
+        }
+        let contents = serde_json::to_vec_pretty(&CacheFile {
+            client_id: &self.client_id,
+            token_endpoint: &self.token_endpoint,
+            session,
+        })
+        .context("Couldn't serialize the tokens")?;
+        write_private(&self.path, &contents)
+            .with_context(|| format!("Couldn't write the token cache {}", self.path.display()))
+    }
+
+    /// Saves the session, only warning on failure since the session itself is still usable.
+    pub(crate) fn save_or_warn(&self, session: &Session) {
+        if let Err(e) = self.save(session) {
+            warn!("{:#}", e);
+        }
+    }
+}
+
+#[cfg(unix)]
+fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // `mode` only applies to new files
+    file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    file.write_all(contents)
+}
+
+#[cfg(not(unix))]
+fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?
+        .write_all(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::scope::ScopeSet;
+
+    #[test]
+    fn only_loads_tokens_of_the_same_app_and_endpoint() {
+        let path = std::env::temp_dir().join(format!("spotifry-test-{}.json", std::process::id()));
+        let cache = TokenCache::new(path.clone(), "app", "https://accounts.example/api/token");
+        cache
+            .save(&Session {
+                access_token: "access".to_owned(),
+                refresh_token: Some("refresh".to_owned()),
+                expires_at: Utc::now(),
+                scope: ScopeSet::new(),
+            })
+            .unwrap();
+
+        let session = cache.load().unwrap().unwrap();
+        assert_eq!(session.refresh_token.as_deref(), Some("refresh"));
+        let other_app =
+            TokenCache::new(path.clone(), "other", "https://accounts.example/api/token");
+        assert!(other_app.load().unwrap().is_none());
+        let other_endpoint =
+            TokenCache::new(path.clone(), "app", "http://127.0.0.1:1234/api/token");
+        assert!(other_endpoint.load().unwrap().is_none());
+
+        // caches from before they were tied to an app are ignored too
+        fs::write(
+            &path,
+            r#"{"access_token": "access", "refresh_token": null, "expires_at": "2021-01-01T00:00:00Z", "scope": ""}"#,
+        )
+        .unwrap();
+        assert!(cache.load().unwrap().is_none());
+
+        fs::remove_file(path).unwrap();
+    }
+}
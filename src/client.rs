@@ -0,0 +1,502 @@
+use std::{io, path::PathBuf};
+
+use reqwest::{
+    header::{HeaderValue, AUTHORIZATION, CONTENT_LENGTH},
+    Client, RequestBuilder, Response, StatusCode,
+};
+use serde::de::DeserializeOwned;
+use tokio::{sync::RwLock, time};
+
+use crate::{
+    auth::{self, Pkce, ACCOUNTS_SERVICE, O_AUTH_ENDPOINT, REDIRECT_PORT},
+    cache::TokenCache,
+    credentials::Credentials,
+    error::{self, Result},
+    retry::RetryPolicy,
+    scope::{Scope, ScopeSet},
+    session::Session,
+};
+
+mod albums;
+mod artists;
+mod follow;
+mod paging;
+mod personalization;
+mod player;
+mod playlists;
+mod search;
+mod tracks;
+
+pub use artists::AlbumGroup;
+pub use personalization::TimeRange;
+pub use player::{Playback, PlayedCursor, RepeatState};
+pub use playlists::{PlaylistDetails, RemovedItem};
+pub use search::{SearchQuery, SearchType};
+
+const WEB_API: &str = "https://api.spotify.com/v1";
+
+/// A client for the Spotify web API, acting on behalf of the user who authorized it,
+/// or of the application itself.
+#[derive(Debug)]
+pub struct SpotifyClient {
+    requester: Client,
+    credentials: Credentials,
+    session: RwLock<Session>,
+    token_cache: Option<TokenCache>,
+    retry_policy: RetryPolicy,
+    api_base: String,
+    token_endpoint: String,
+}
+
+impl SpotifyClient {
+    /// Asks the user to authorize access to their data, and creates a client on their behalf.
+    pub async fn authorize() -> Result<Self> {
+        Self::builder().authorize().await
+    }
+
+    /// Creates a builder to configure the client with, e.g. to target something other than Spotify.
+    pub fn builder() -> SpotifyClientBuilder {
+        SpotifyClientBuilder::default()
+    }
+
+    /// Creates a client acting on behalf of the application itself, without any user interaction.
+    /// It can only access public data, such as the catalog of tracks, albums, artists and playlists.
+    pub async fn client_credentials() -> Result<Self> {
+        Self::builder().client_credentials().await
+    }
+
+    /// Trades the refresh token for a new access token.
+    /// This happens automatically when the access token is about to expire, or gets rejected.
+    pub async fn refresh_tokens(&self) -> Result<()> {
+        let mut session = self.session.write().await;
+        self.refresh(&mut session).await
+    }
+
+    async fn refresh(&self, session: &mut Session) -> Result<()> {
+        let tokens = match &session.refresh_token {
+            Some(refresh_token) => {
+                auth::refresh_tokens(
+                    &self.requester,
+                    &self.token_endpoint,
+                    &self.credentials,
+                    refresh_token,
+                )
+                .await?
+            }
+            None => {
+                auth::get_app_tokens(&self.requester, &self.token_endpoint, &self.credentials)
+                    .await?
+            }
+        };
+        session.update(tokens);
+        if let Some(token_cache) = &self.token_cache {
+            token_cache.save_or_warn(session);
+        }
+        Ok(())
+    }
+
+    /// The scopes the user has granted the client.
+    pub async fn granted_scopes(&self) -> ScopeSet {
+        self.session.read().await.scope.clone()
+    }
+
+    /// Fails fast if `scope` wasn't granted, rather than letting Spotify reject the request.
+    async fn require(&self, scope: Scope) -> Result<()> {
+        Ok(self.session.read().await.scope.require(scope)?)
+    }
+
+    /// Fails fast if none of `scopes` were granted, e.g. when either of two would do.
+    async fn require_any(&self, scopes: &[Scope]) -> Result<()> {
+        Ok(self.session.read().await.scope.require_any(scopes)?)
+    }
+
+    /// An access token that isn't about to expire, refreshed if needed.
+    async fn access_token(&self) -> Result<String> {
+        {
+            let session = self.session.read().await;
+            if !session.is_stale() {
+                return Ok(session.access_token.clone());
+            }
+        }
+        let mut session = self.session.write().await;
+        // another request might have refreshed while we waited for the lock
+        if session.is_stale() {
+            self.refresh(&mut session).await?;
+        }
+        Ok(session.access_token.clone())
+    }
+
+    /// Sends an authorized request, which every endpoint goes through.
+    /// If the access token is rejected, the tokens are refreshed and the request is retried once.
+    /// Rate limited requests and server errors are retried as the [`RetryPolicy`] says.
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        // fails here rather than when sending if the URL is invalid, e.g. because of a bad `api_base`
+        let request = request.build()?;
+        let mut access_token = self.access_token().await?;
+        let mut refreshed = false;
+        let mut retries = 0;
+        loop {
+            let mut attempt = request.try_clone().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "Can't retry a streamed request",
+                )
+            })?;
+            attempt
+                .headers_mut()
+                .insert(AUTHORIZATION, bearer(&access_token)?);
+            let response = self.requester.execute(attempt).await?;
+            let status = response.status();
+
+            if status == StatusCode::UNAUTHORIZED && !refreshed {
+                let mut session = self.session.write().await;
+                // another request might have refreshed already
+                if session.access_token == access_token {
+                    self.refresh(&mut session).await?;
+                }
+                access_token = session.access_token.clone();
+                refreshed = true;
+                continue;
+            }
+
+            match self
+                .retry_policy
+                .delay(status, error::retry_after(&response), retries)
+            {
+                Some(delay) => {
+                    time::sleep(delay).await;
+                    retries += 1;
+                }
+                None => return Ok(response),
+            }
+        }
+    }
+
+    /// Sends an authorized request, and deserializes the response.
+    async fn fetch<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        error::json(self.send(request).await?).await
+    }
+
+    /// Sends an authorized request, only caring whether it succeeded.
+    async fn execute(&self, request: RequestBuilder) -> Result<()> {
+        error::error_for_status(self.send(request).await?).await?;
+        Ok(())
+    }
+
+    /// A `GET` request to a Web API endpoint.
+    fn get(&self, path: &str) -> RequestBuilder {
+        self.requester.get(self.endpoint(path))
+    }
+
+    /// A `POST` request to a Web API endpoint.
+    fn post(&self, path: &str) -> RequestBuilder {
+        self.requester.post(self.endpoint(path))
+    }
+
+    /// A `PUT` request to a Web API endpoint.
+    fn put(&self, path: &str) -> RequestBuilder {
+        self.requester.put(self.endpoint(path))
+    }
+
+    /// A `DELETE` request to a Web API endpoint.
+    fn delete(&self, path: &str) -> RequestBuilder {
+        self.requester.delete(self.endpoint(path))
+    }
+
+    /// The full URL of a Web API endpoint.
+    fn endpoint(&self, path: &str) -> String {
+        format!("{}/{}", self.api_base.trim_end_matches('/'), path)
+    }
+}
+
+/// The `Authorization` header for `access_token`.
+fn bearer(access_token: &str) -> Result<HeaderValue> {
+    let mut value = HeaderValue::from_str(&format!("Bearer {}", access_token))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Marks a `PUT` or `POST` as having no body,
+/// as Spotify answers them with 411 Length Required if they don't say how long the body is.
+fn without_body(request: RequestBuilder) -> RequestBuilder {
+    request.header(CONTENT_LENGTH, 0)
+}
+
+/// Splits `ids` into the comma-separated lists of at most `size` IDs that endpoints taking several of them expect.
+fn id_chunks<'a>(ids: &'a [&str], size: usize) -> impl Iterator<Item = String> + 'a {
+    ids.chunks(size).map(|chunk| chunk.join(","))
+}
+
+/// Configures a [`SpotifyClient`] before it is authorized.
+#[derive(Debug, Clone)]
+pub struct SpotifyClientBuilder {
+    credentials: Option<Credentials>,
+    scopes: ScopeSet,
+    pkce: bool,
+    redirect_port: u16,
+    cache_tokens: bool,
+    token_cache: Option<PathBuf>,
+    retry_policy: RetryPolicy,
+    api_base: String,
+    authorize_endpoint: String,
+    token_endpoint: String,
+}
+
+impl Default for SpotifyClientBuilder {
+    fn default() -> Self {
+        Self {
+            credentials: None,
+            scopes: ScopeSet::new(),
+            pkce: false,
+            redirect_port: REDIRECT_PORT,
+            cache_tokens: true,
+            token_cache: None,
+            retry_policy: RetryPolicy::default(),
+            api_base: WEB_API.to_owned(),
+            authorize_endpoint: O_AUTH_ENDPOINT.to_owned(),
+            token_endpoint: ACCOUNTS_SERVICE.to_owned(),
+        }
+    }
+}
+
+impl SpotifyClientBuilder {
+    /// The credentials of the application to authorize, loaded with [`Credentials::load`] if not set.
+    pub fn credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Adds scopes to ask the user to grant; none are asked for by default.
+    pub fn scopes(mut self, scopes: impl IntoIterator<Item = Scope>) -> Self {
+        self.scopes.extend(scopes);
+        self
+    }
+
+    /// Authorize with the Authorization Code with PKCE flow, which doesn't need the client secret.
+    /// The secret isn't used even if the credentials have one.
+    pub fn pkce(mut self) -> Self {
+        self.pkce = true;
+        self
+    }
+
+    /// The port of the local server the user is redirected to after authorizing, `8888` by default.
+    /// The redirect URI `http://127.0.0.1:<port>/auth/callback/spotify` has to be whitelisted in the dashboard.
+    /// `0` picks any free port, which only works if Spotify accepts any port for loopback redirects.
+    pub fn redirect_port(mut self, redirect_port: u16) -> Self {
+        self.redirect_port = redirect_port;
+        self
+    }
+
+    /// The file tokens are cached in between runs, `spotifry/tokens.json` in the user's config directory by default.
+    /// Tokens are only cached by default when talking to Spotify itself, so set one to cache them with other endpoints.
+    pub fn token_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache_tokens = true;
+        self.token_cache = Some(path.into());
+        self
+    }
+
+    /// Don't cache tokens, so the user is asked to authorize every time.
+    pub fn without_token_cache(mut self) -> Self {
+        self.cache_tokens = false;
+        self.token_cache = None;
+        self
+    }
+
+    /// How rate limited requests and server errors are retried, see [`RetryPolicy::default`].
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// The base URL of the Web API, `https://api.spotify.com/v1` by default.
+    /// Overriding it disables the default token cache, see [`SpotifyClientBuilder::token_cache`].
+    pub fn api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into();
+        self
+    }
+
+    /// The URL of the Accounts service `/authorize` endpoint, `https://accounts.spotify.com/authorize` by default.
+    pub fn authorize_endpoint(mut self, authorize_endpoint: impl Into<String>) -> Self {
+        self.authorize_endpoint = authorize_endpoint.into();
+        self
+    }
+
+    /// The URL of the Accounts service `/api/token` endpoint, `https://accounts.spotify.com/api/token` by default.
+    /// Overriding it disables the default token cache, see [`SpotifyClientBuilder::token_cache`].
+    pub fn token_endpoint(mut self, token_endpoint: impl Into<String>) -> Self {
+        self.token_endpoint = token_endpoint.into();
+        self
+    }
+
+    /// Asks the user to authorize access to their data, and creates a client on their behalf.
+    /// Cached tokens are reused instead when they grant the needed scopes, being refreshed if stale.
+    pub async fn authorize(self) -> Result<SpotifyClient> {
+        let Self {
+            credentials,
+            scopes,
+            pkce,
+            redirect_port,
+            cache_tokens,
+            token_cache,
+            retry_policy,
+            api_base,
+            authorize_endpoint,
+            token_endpoint,
+        } = self;
+
+        let credentials = match credentials {
+            Some(credentials) => credentials,
+            None => Credentials::load()?,
+        };
+        // the rest of the client doesn't need to know about the flow, it just won't send a secret
+        let credentials = if pkce {
+            credentials.into_public()
+        } else {
+            credentials.require_secret()?;
+            credentials
+        };
+        // tokens from some other API, like a fake one, shouldn't mix with the user's real ones
+        let token_cache = match token_cache {
+            _ if !cache_tokens => None,
+            Some(path) => Some(path),
+            None if api_base == WEB_API && token_endpoint == ACCOUNTS_SERVICE => {
+                TokenCache::default_path()
+            }
+            None => None,
+        }
+        .map(|path| TokenCache::new(path, credentials.client_id(), &token_endpoint));
+
+        let requester = Client::builder().build()?;
+
+        let cached = match &token_cache {
+            Some(token_cache) => {
+                restore_session(
+                    &requester,
+                    &token_endpoint,
+                    &credentials,
+                    &scopes,
+                    token_cache,
+                )
+                .await
+            }
+            None => None,
+        };
+        let session = match cached {
+            Some(session) => session,
+            None => {
+                authorize_user(
+                    &requester,
+                    &authorize_endpoint,
+                    &token_endpoint,
+                    &credentials,
+                    &scopes,
+                    redirect_port,
+                )
+                .await?
+            }
+        };
+        if let Some(token_cache) = &token_cache {
+            token_cache.save_or_warn(&session);
+        }
+
+        Ok(SpotifyClient {
+            requester,
+            credentials,
+            session: RwLock::new(session),
+            token_cache,
+            retry_policy,
+            api_base,
+            token_endpoint,
+        })
+    }
+
+    /// Creates a client acting on behalf of the application itself, without any user interaction.
+    /// It can only access public data, such as the catalog of tracks, albums, artists and playlists.
+    /// This flow requires the client secret, and its tokens aren't cached.
+    pub async fn client_credentials(self) -> Result<SpotifyClient> {
+        let credentials = match self.credentials {
+            Some(credentials) => credentials,
+            None => Credentials::load()?,
+        };
+
+        let requester = Client::builder().build()?;
+
+        let tokens = auth::get_app_tokens(&requester, &self.token_endpoint, &credentials).await?;
+
+        Ok(SpotifyClient {
+            requester,
+            credentials,
+            session: RwLock::new(Session::new(tokens)),
+            token_cache: None,
+            retry_policy: self.retry_policy,
+            api_base: self.api_base,
+            token_endpoint: self.token_endpoint,
+        })
+    }
+}
+
+/// The cached session, if it grants the needed scopes and is either fresh or can be refreshed.
+async fn restore_session(
+    requester: &Client,
+    token_endpoint: &str,
+    credentials: &Credentials,
+    scopes: &ScopeSet,
+    token_cache: &TokenCache,
+) -> Option<Session> {
+    let mut session = match token_cache.load() {
+        Ok(session) => session?,
+        Err(e) => {
+            warn!("Ignoring the token cache: {:#}", e);
+            return None;
+        }
+    };
+    if !session.scope.is_superset(scopes) {
+        return None;
+    }
+    if session.is_stale() {
+        let refresh_token = session.refresh_token.as_deref()?;
+        match auth::refresh_tokens(requester, token_endpoint, credentials, refresh_token).await {
+            Ok(tokens) => session.update(tokens),
+            Err(e) => {
+                warn!("Couldn't refresh the cached tokens: {:#}", e);
+                return None;
+            }
+        }
+    }
+    Some(session)
+}
+
+/// Goes through the whole authorization flow, with the user authorizing access in their browser.
+/// Credentials without a secret authorize with PKCE.
+async fn authorize_user(
+    requester: &Client,
+    authorize_endpoint: &str,
+    token_endpoint: &str,
+    credentials: &Credentials,
+    scopes: &ScopeSet,
+    redirect_port: u16,
+) -> Result<Session> {
+    let pkce = credentials.client_secret().is_none().then(Pkce::new);
+
+    let authorization = auth::authorize_scope(
+        requester,
+        authorize_endpoint,
+        credentials.client_id(),
+        scopes,
+        pkce.as_ref(),
+        redirect_port,
+    )
+    .await?;
+
+    let tokens = auth::get_tokens(
+        requester,
+        token_endpoint,
+        credentials,
+        &authorization,
+        pkce.as_ref().map(|pkce| &*pkce.verifier),
+    )
+    .await?;
+
+    Ok(Session::new(tokens))
+}
@@ -0,0 +1,108 @@
+use common_macros::hash_map;
+use serde::Deserialize;
+
+use super::{id_chunks, without_body, SpotifyClient};
+use crate::{
+    error::Result,
+    objects::{AlbumObject, PagingObject, SavedAlbumObject, SimplifiedTrackObject},
+    scope::Scope,
+};
+
+/// The most albums the album endpoints take at once.
+const MAX_ALBUMS: usize = 20;
+
+impl SpotifyClient {
+    /// Get Spotify catalog information for a single album.
+    pub async fn album(&self, id: &str) -> Result<AlbumObject> {
+        self.fetch(self.get(&format!("albums/{}", id))).await
+    }
+
+    /// Get Spotify catalog information for multiple albums identified by their Spotify IDs.
+    /// Albums that couldn't be found are `None`, in the same position as their ID.
+    pub async fn albums(&self, ids: &[&str]) -> Result<Vec<Option<AlbumObject>>> {
+        let mut albums = Vec::with_capacity(ids.len());
+        for ids in id_chunks(ids, MAX_ALBUMS) {
+            let several: SeveralAlbums = self
+                .fetch(self.get("albums").query(&hash_map! { "ids" => ids }))
+                .await?;
+            albums.extend(several.albums);
+        }
+        Ok(albums)
+    }
+
+    /// Get Spotify catalog information about an album’s tracks.
+    pub async fn album_tracks(
+        &self,
+        id: &str,
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<SimplifiedTrackObject>> {
+        self.fetch(
+            self.get(&format!("albums/{}/tracks", id))
+                .query(&hash_map! {
+                    "offset" => offset.to_string(),
+                    "limit" => limit.to_string(),
+                }),
+        )
+        .await
+    }
+
+    /// Get a list of the albums saved in the current Spotify user’s ‘Your Music’ library.
+    pub async fn saved_albums(
+        &self,
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<SavedAlbumObject>> {
+        self.require(Scope::UserLibraryRead).await?;
+        self.fetch(self.get("me/albums").query(&hash_map! {
+            "offset" => offset.to_string(),
+            "limit" => limit.to_string(),
+        }))
+        .await
+    }
+
+    /// Save one or more albums to the current user’s ‘Your Music’ library.
+    pub async fn save_albums(&self, ids: &[&str]) -> Result<()> {
+        self.require(Scope::UserLibraryModify).await?;
+        for ids in id_chunks(ids, MAX_ALBUMS) {
+            self.execute(without_body(
+                self.put("me/albums").query(&hash_map! { "ids" => ids }),
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Remove one or more albums from the current user’s ‘Your Music’ library.
+    pub async fn remove_saved_albums(&self, ids: &[&str]) -> Result<()> {
+        self.require(Scope::UserLibraryModify).await?;
+        for ids in id_chunks(ids, MAX_ALBUMS) {
+            self.execute(self.delete("me/albums").query(&hash_map! { "ids" => ids }))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Check if one or more albums is already saved in the current Spotify user’s ‘Your Music’ library.
+    /// Each album gets a `bool`, in the same order as the IDs.
+    pub async fn check_saved_albums(&self, ids: &[&str]) -> Result<Vec<bool>> {
+        self.require(Scope::UserLibraryRead).await?;
+        let mut saved = Vec::with_capacity(ids.len());
+        for ids in id_chunks(ids, MAX_ALBUMS) {
+            let chunk: Vec<bool> = self
+                .fetch(
+                    self.get("me/albums/contains")
+                        .query(&hash_map! { "ids" => ids }),
+                )
+                .await?;
+            saved.extend(chunk);
+        }
+        Ok(saved)
+    }
+}
+
+/// Several albums come wrapped in an object.
+#[derive(Debug, Deserialize)]
+struct SeveralAlbums {
+    albums: Vec<Option<AlbumObject>>,
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+
+use common_macros::hash_map;
+use serde::Deserialize;
+
+use super::{id_chunks, SpotifyClient};
+use crate::{
+    error::Result,
+    objects::{ArtistObject, PagingObject, SimplifiedAlbumObject, TrackObject},
+};
+
+/// The most artists Get Several Artists takes at once.
+const MAX_ARTISTS: usize = 50;
+
+/// How an artist is related to an album, to filter [`SpotifyClient::artist_albums`] by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlbumGroup {
+    /// The artist's own albums.
+    Album,
+    /// The artist's own singles and EPs.
+    Single,
+    /// Albums by others the artist appears on.
+    AppearsOn,
+    /// Compilations the artist is part of.
+    Compilation,
+}
+
+impl AlbumGroup {
+    /// The name Spotify knows the group by, e.g. `appears_on`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AlbumGroup::Album => "album",
+            AlbumGroup::Single => "single",
+            AlbumGroup::AppearsOn => "appears_on",
+            AlbumGroup::Compilation => "compilation",
+        }
+    }
+}
+
+impl fmt::Display for AlbumGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl SpotifyClient {
+    /// Get Spotify catalog information for a single artist identified by their unique Spotify ID.
+    pub async fn artist(&self, id: &str) -> Result<ArtistObject> {
+        self.fetch(self.get(&format!("artists/{}", id))).await
+    }
+
+    /// Get Spotify catalog information for several artists based on their Spotify IDs.
+    /// Artists that couldn't be found are `None`, in the same position as their ID.
+    pub async fn artists(&self, ids: &[&str]) -> Result<Vec<Option<ArtistObject>>> {
+        let mut artists = Vec::with_capacity(ids.len());
+        for ids in id_chunks(ids, MAX_ARTISTS) {
+            let several: SeveralArtists = self
+                .fetch(self.get("artists").query(&hash_map! { "ids" => ids }))
+                .await?;
+            artists.extend(several.artists);
+        }
+        Ok(artists)
+    }
+
+    /// Get Spotify catalog information about an artist’s albums.
+    /// Only albums in `include_groups` are returned, or all of them if it's empty.
+    pub async fn artist_albums(
+        &self,
+        id: &str,
+        include_groups: &[AlbumGroup],
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<SimplifiedAlbumObject>> {
+        let mut query = hash_map! {
+            "offset" => offset.to_string(),
+            "limit" => limit.to_string(),
+        };
+        if !include_groups.is_empty() {
+            let groups: Vec<_> = include_groups.iter().map(|group| group.as_str()).collect();
+            query.insert("include_groups", groups.join(","));
+        }
+        self.fetch(self.get(&format!("artists/{}/albums", id)).query(&query))
+            .await
+    }
+
+    /// Get Spotify catalog information about an artist’s top tracks by country,
+    /// `market` being an [ISO 3166-1 alpha-2](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) country code.
+    pub async fn artist_top_tracks(&self, id: &str, market: &str) -> Result<Vec<TrackObject>> {
+        let top: TopTracks = self
+            .fetch(
+                self.get(&format!("artists/{}/top-tracks", id))
+                    .query(&hash_map! { "market" => market }),
+            )
+            .await?;
+        Ok(top.tracks)
+    }
+
+    /// Get Spotify catalog information about artists similar to a given artist.
+    /// Similarity is based on analysis of the Spotify community’s listening history.
+    pub async fn related_artists(&self, id: &str) -> Result<Vec<ArtistObject>> {
+        let related: RelatedArtists = self
+            .fetch(self.get(&format!("artists/{}/related-artists", id)))
+            .await?;
+        Ok(related.artists)
+    }
+}
+
+/// Several artists come wrapped in an object.
+#[derive(Debug, Deserialize)]
+struct SeveralArtists {
+    artists: Vec<Option<ArtistObject>>,
+}
+
+/// Top tracks come wrapped in an object.
+#[derive(Debug, Deserialize)]
+struct TopTracks {
+    tracks: Vec<TrackObject>,
+}
+
+/// Related artists come wrapped in an object.
+#[derive(Debug, Deserialize)]
+struct RelatedArtists {
+    artists: Vec<ArtistObject>,
+}
@@ -0,0 +1,35 @@
+use common_macros::hash_map;
+use serde::Deserialize;
+
+use super::SpotifyClient;
+use crate::{
+    error::Result,
+    objects::{ArtistObject, CursorPagingObject},
+    scope::Scope,
+};
+
+impl SpotifyClient {
+    /// Get the current user’s followed artists, starting after the artist with the ID `after`.
+    pub async fn followed_artists(
+        &self,
+        after: Option<&str>,
+        limit: u8,
+    ) -> Result<CursorPagingObject<ArtistObject>> {
+        self.require(Scope::UserFollowRead).await?;
+        let mut query = hash_map! {
+            "type" => "artist".to_owned(),
+            "limit" => limit.to_string(),
+        };
+        if let Some(after) = after {
+            query.insert("after", after.to_owned());
+        }
+        let followed: FollowedArtists = self.fetch(self.get("me/following").query(&query)).await?;
+        Ok(followed.artists)
+    }
+}
+
+/// The followed artists come wrapped in an object.
+#[derive(Debug, Deserialize)]
+struct FollowedArtists {
+    artists: CursorPagingObject<ArtistObject>,
+}
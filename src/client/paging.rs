@@ -0,0 +1,86 @@
+use std::{collections::HashMap, future::Future};
+
+use futures::{future, stream, Stream, StreamExt, TryStreamExt};
+use serde::{de::Error as _, Deserialize};
+
+use super::SpotifyClient;
+use crate::{
+    error::{Error, Result},
+    objects::{Page, PagingObject},
+};
+
+/// The most items most paged endpoints return at once.
+pub(crate) const MAX_PAGE_SIZE: u8 = 50;
+
+impl SpotifyClient {
+    /// Streams the items of `page`, and of every page after it, fetching each page only once its items run out.
+    /// Works with the first page of any paged endpoint, offset or cursor based,
+    /// e.g. `client.paginate(client.saved_tracks(0, 50).await?)`.
+    pub fn paginate<'a, P>(&'a self, page: P) -> impl Stream<Item = Result<P::Item>> + 'a
+    where
+        P: Page + 'a,
+    {
+        let next = page.next_page().map(str::to_owned);
+        let rest = stream::try_unfold(next, move |next| async move {
+            let next = match next {
+                Some(next) => next,
+                None => return Ok::<_, Error>(None),
+            };
+            let page = match self
+                .fetch::<MaybeWrapped<P>>(self.requester.get(&next))
+                .await?
+            {
+                MaybeWrapped::Page(page) => page,
+                MaybeWrapped::Wrapped(wrapped) => {
+                    wrapped.into_values().next().ok_or_else(|| {
+                        serde_json::Error::custom("expected an object wrapping the next page")
+                    })?
+                }
+            };
+            let after = page.next_page().map(str::to_owned);
+            Ok(Some((page, after)))
+        });
+
+        stream::once(future::ready(Ok(page)))
+            .chain(rest)
+            .map_ok(|page| stream::iter(page.into_page_items().into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Fetches the first page with `fetch_page(offset, limit)`, and once it tells the total,
+    /// all the others with up to `concurrency` requests at once, streaming their items in order.
+    pub(crate) fn fetch_pages<'a, T, F, Fut>(
+        &'a self,
+        concurrency: usize,
+        fetch_page: F,
+    ) -> impl Stream<Item = Result<T>> + 'a
+    where
+        T: 'a,
+        F: Fn(usize, u8) -> Fut + Clone + 'a,
+        Fut: Future<Output = Result<PagingObject<T>>> + 'a,
+    {
+        stream::once(fetch_page(0, MAX_PAGE_SIZE))
+            .map_ok(move |first| {
+                let fetch_page = fetch_page.clone();
+                // Spotify might return fewer items than asked for
+                let limit = first.limit.max(1);
+                let offsets =
+                    (first.offset + usize::from(limit)..first.total).step_by(usize::from(limit));
+                let rest = stream::iter(offsets)
+                    .map(move |offset| fetch_page(offset, limit))
+                    .buffered(concurrency.max(1));
+                stream::once(future::ready(Ok(first))).chain(rest)
+            })
+            .try_flatten()
+            .map_ok(|page| stream::iter(page.into_items().into_iter().map(Ok)))
+            .try_flatten()
+    }
+}
+
+/// Some endpoints wrap their pages in an object, like `{"artists": {...}}`, and so do their `next` links.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum MaybeWrapped<P> {
+    Page(P),
+    Wrapped(HashMap<String, P>),
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+
+use common_macros::hash_map;
+use serde::de::DeserializeOwned;
+
+use super::SpotifyClient;
+use crate::{
+    error::Result,
+    objects::{ArtistObject, PagingObject, TrackObject},
+    scope::Scope,
+};
+
+/// Over what time frame the user's affinities are computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeRange {
+    /// Approximately the last 4 weeks.
+    ShortTerm,
+    /// Approximately the last 6 months, which is what Spotify uses by default.
+    MediumTerm,
+    /// Several years of data, including all new data as it becomes available.
+    LongTerm,
+}
+
+impl TimeRange {
+    /// The name Spotify knows the time range by, e.g. `short_term`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TimeRange::ShortTerm => "short_term",
+            TimeRange::MediumTerm => "medium_term",
+            TimeRange::LongTerm => "long_term",
+        }
+    }
+}
+
+impl fmt::Display for TimeRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl SpotifyClient {
+    /// Get the current user’s top artists based on calculated affinity, most affine first.
+    /// All of them can be streamed with [`SpotifyClient::paginate`].
+    pub async fn top_artists(
+        &self,
+        time_range: TimeRange,
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<ArtistObject>> {
+        self.top_items("artists", time_range, offset, limit).await
+    }
+
+    /// Get the current user’s top tracks based on calculated affinity, most affine first.
+    /// All of them can be streamed with [`SpotifyClient::paginate`].
+    pub async fn top_tracks(
+        &self,
+        time_range: TimeRange,
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<TrackObject>> {
+        self.top_items("tracks", time_range, offset, limit).await
+    }
+
+    async fn top_items<T: DeserializeOwned>(
+        &self,
+        r#type: &str,
+        time_range: TimeRange,
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<T>> {
+        self.require(Scope::UserTopRead).await?;
+        self.fetch(self.get(&format!("me/top/{}", r#type)).query(&hash_map! {
+            "time_range" => time_range.as_str().to_owned(),
+            "offset" => offset.to_string(),
+            "limit" => limit.to_string(),
+        }))
+        .await
+    }
+}
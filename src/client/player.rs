@@ -0,0 +1,301 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use common_macros::hash_map;
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use super::{without_body, SpotifyClient};
+use crate::{
+    error::{self, Result},
+    objects::{
+        CurrentlyPlayingContextObject, CursorPagingObject, DeviceObject, PlayHistoryObject,
+        QueueObject,
+    },
+    scope::Scope,
+};
+
+/// What the player repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RepeatState {
+    /// Repeat the current track.
+    Track,
+    /// Repeat the current context, e.g. the album or playlist.
+    Context,
+    /// Don't repeat anything.
+    Off,
+}
+
+impl RepeatState {
+    /// The name Spotify knows the state by, e.g. `context`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RepeatState::Track => "track",
+            RepeatState::Context => "context",
+            RepeatState::Off => "off",
+        }
+    }
+}
+
+impl fmt::Display for RepeatState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Where to start in the recently played tracks, as Spotify only takes one of the two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlayedCursor {
+    /// Only tracks played after this time.
+    After(DateTime<Utc>),
+    /// Only tracks played before this time.
+    Before(DateTime<Utc>),
+}
+
+/// What to start playing: an album, artist or playlist, or a list of tracks and episodes.
+#[derive(Debug, Clone, Serialize)]
+pub struct Playback {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uris: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<PlaybackOffset>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position_ms: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+enum PlaybackOffset {
+    Position { position: usize },
+    Uri { uri: String },
+}
+
+impl Playback {
+    /// Play the album, artist or playlist with the [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) `context_uri`.
+    pub fn context(context_uri: impl Into<String>) -> Self {
+        Self {
+            context_uri: Some(context_uri.into()),
+            uris: None,
+            offset: None,
+            position_ms: None,
+        }
+    }
+
+    /// Play the tracks and episodes with these [Spotify URIs](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids).
+    pub fn uris(uris: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            context_uri: None,
+            uris: Some(uris.into_iter().map(Into::into).collect()),
+            offset: None,
+            position_ms: None,
+        }
+    }
+
+    /// Start at the item at `position` (zero-based) rather than the first one.
+    pub fn offset_position(mut self, position: usize) -> Self {
+        self.offset = Some(PlaybackOffset::Position { position });
+        self
+    }
+
+    /// Start at the item with the URI `uri` rather than the first one.
+    pub fn offset_uri(mut self, uri: impl Into<String>) -> Self {
+        self.offset = Some(PlaybackOffset::Uri { uri: uri.into() });
+        self
+    }
+
+    /// Start `position_ms` milliseconds into the first item.
+    pub fn position_ms(mut self, position_ms: usize) -> Self {
+        self.position_ms = Some(position_ms);
+        self
+    }
+}
+
+// commands are sent to the device with `device_id`, or to the active one if it's `None`
+impl SpotifyClient {
+    /// Get information about the user’s current playback state, including track or episode, progress, and active device.
+    /// `None` if nothing is playing.
+    pub async fn playback_state(&self) -> Result<Option<CurrentlyPlayingContextObject>> {
+        self.require(Scope::UserReadPlaybackState).await?;
+        let response = self
+            .send(
+                self.get("me/player")
+                    .query(&hash_map! { "additional_types" => "episode" }),
+            )
+            .await?;
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        error::json(response).await
+    }
+
+    /// Get information about a user’s available devices.
+    pub async fn devices(&self) -> Result<Vec<DeviceObject>> {
+        self.require(Scope::UserReadPlaybackState).await?;
+        let devices: Devices = self.fetch(self.get("me/player/devices")).await?;
+        Ok(devices.devices)
+    }
+
+    /// Transfer playback to a new device, and make it start playing if `play`, rather than keeping the current state.
+    pub async fn transfer_playback(&self, device_id: &str, play: bool) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(self.put("me/player").json(&TransferPlayback {
+            device_ids: [device_id],
+            play,
+        }))
+        .await
+    }
+
+    /// Start playing something new.
+    pub async fn start_playback(&self, playback: &Playback, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(on_device(self.put("me/player/play"), device_id).json(playback))
+            .await
+    }
+
+    /// Resume playing whatever is paused.
+    pub async fn resume_playback(&self, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(without_body(on_device(
+            self.put("me/player/play"),
+            device_id,
+        )))
+        .await
+    }
+
+    /// Pause playback on the user’s account.
+    pub async fn pause_playback(&self, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(without_body(on_device(
+            self.put("me/player/pause"),
+            device_id,
+        )))
+        .await
+    }
+
+    /// Skips to next track in the user’s queue.
+    pub async fn skip_to_next(&self, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(without_body(on_device(
+            self.post("me/player/next"),
+            device_id,
+        )))
+        .await
+    }
+
+    /// Skips to previous track in the user’s queue.
+    pub async fn skip_to_previous(&self, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(without_body(on_device(
+            self.post("me/player/previous"),
+            device_id,
+        )))
+        .await
+    }
+
+    /// Seeks to `position_ms` milliseconds into the currently playing track or episode.
+    pub async fn seek(&self, position_ms: usize, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(without_body(on_device(
+            self.put("me/player/seek")
+                .query(&hash_map! { "position_ms" => position_ms }),
+            device_id,
+        )))
+        .await
+    }
+
+    /// Set the volume for the user’s current playback device, from 0 to 100 percent.
+    pub async fn set_volume(&self, volume_percent: u8, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(without_body(on_device(
+            self.put("me/player/volume")
+                .query(&hash_map! { "volume_percent" => volume_percent }),
+            device_id,
+        )))
+        .await
+    }
+
+    /// Set the repeat mode for the user’s playback.
+    pub async fn set_repeat(&self, state: RepeatState, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(without_body(on_device(
+            self.put("me/player/repeat")
+                .query(&hash_map! { "state" => state.as_str() }),
+            device_id,
+        )))
+        .await
+    }
+
+    /// Toggle shuffle on or off for user’s playback.
+    pub async fn set_shuffle(&self, state: bool, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(without_body(on_device(
+            self.put("me/player/shuffle")
+                .query(&hash_map! { "state" => state }),
+            device_id,
+        )))
+        .await
+    }
+
+    /// Get tracks from the current user’s recently played tracks, most recent first.
+    /// Only tracks played on the `cursor`'s side of its time are returned, or the latest ones if it's `None`;
+    /// the `next` page continues further back in time.
+    pub async fn recently_played(
+        &self,
+        cursor: Option<PlayedCursor>,
+        limit: u8,
+    ) -> Result<CursorPagingObject<PlayHistoryObject>> {
+        self.require(Scope::UserReadRecentlyPlayed).await?;
+        let mut query = hash_map! { "limit" => limit.to_string() };
+        match cursor {
+            Some(PlayedCursor::After(after)) => {
+                query.insert("after", after.timestamp_millis().to_string());
+            }
+            Some(PlayedCursor::Before(before)) => {
+                query.insert("before", before.timestamp_millis().to_string());
+            }
+            None => {}
+        }
+        self.fetch(self.get("me/player/recently-played").query(&query))
+            .await
+    }
+
+    /// Get the currently playing item and the ones queued after it.
+    pub async fn queue(&self) -> Result<QueueObject> {
+        self.require(Scope::UserReadPlaybackState).await?;
+        self.fetch(self.get("me/player/queue")).await
+    }
+
+    /// Add the track or episode with the [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) `uri`
+    /// to the end of the user's queue.
+    pub async fn add_to_queue(&self, uri: &str, device_id: Option<&str>) -> Result<()> {
+        self.require(Scope::UserModifyPlaybackState).await?;
+        self.execute(without_body(on_device(
+            self.post("me/player/queue")
+                .query(&hash_map! { "uri" => uri }),
+            device_id,
+        )))
+        .await
+    }
+}
+
+/// Targets the device with `device_id`, or the active one if it's `None`.
+fn on_device(request: RequestBuilder, device_id: Option<&str>) -> RequestBuilder {
+    match device_id {
+        Some(device_id) => request.query(&hash_map! { "device_id" => device_id }),
+        None => request,
+    }
+}
+
+/// The devices come wrapped in an object.
+#[derive(Debug, Deserialize)]
+struct Devices {
+    devices: Vec<DeviceObject>,
+}
+
+#[derive(Debug, Serialize)]
+struct TransferPlayback<'a> {
+    device_ids: [&'a str; 1],
+    play: bool,
+}
@@ -0,0 +1,336 @@
+use common_macros::hash_map;
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+
+use super::SpotifyClient;
+use crate::{
+    error::Result,
+    objects::{PagingObject, PlaylistObject, PlaylistTrackObject, SimplifiedPlaylistObject},
+    scope::Scope,
+};
+
+/// Playlists can hold episodes too, but Spotify only returns them as such when asked to.
+const ADDITIONAL_TYPES: &str = "track,episode";
+
+/// The most items that can be added to or removed from a playlist at once.
+const MAX_ITEMS: usize = 100;
+
+/// Either of these lets the user's playlists be modified, depending on whether they are public.
+const MODIFY_SCOPES: [Scope; 2] = [Scope::PlaylistModifyPublic, Scope::PlaylistModifyPrivate];
+
+/// The details of a playlist to set when creating or changing it. Anything left unset stays as it is.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PlaylistDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    public: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collaborative: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl PlaylistDetails {
+    /// No details, to be set with the other methods.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The name of the playlist.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether the playlist is displayed on the user's profile. New playlists are public by default.
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = Some(public);
+        self
+    }
+
+    /// Whether other users can modify the playlist. Only private playlists can be collaborative.
+    pub fn collaborative(mut self, collaborative: bool) -> Self {
+        self.collaborative = Some(collaborative);
+        self
+    }
+
+    /// The description shown in Spotify clients.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The scopes any of which allow ending up with a playlist like this.
+    fn modify_scopes(&self) -> &'static [Scope] {
+        match (self.public, self.collaborative) {
+            (Some(false), _) | (_, Some(true)) => &[Scope::PlaylistModifyPrivate],
+            (Some(true), _) => &[Scope::PlaylistModifyPublic],
+            (None, _) => &MODIFY_SCOPES,
+        }
+    }
+}
+
+/// An item to remove from a playlist: every occurrence of it, or only the ones at the given positions.
+#[derive(Debug, Clone, Serialize)]
+pub struct RemovedItem {
+    uri: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    positions: Vec<usize>,
+}
+
+impl RemovedItem {
+    /// Every occurrence of the track or episode with the [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) `uri`.
+    pub fn all(uri: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            positions: Vec::new(),
+        }
+    }
+
+    /// The occurrences at `positions` (zero-based) of the track or episode with the [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) `uri`.
+    pub fn at(uri: impl Into<String>, positions: impl IntoIterator<Item = usize>) -> Self {
+        Self {
+            uri: uri.into(),
+            positions: positions.into_iter().collect(),
+        }
+    }
+}
+
+impl SpotifyClient {
+    /// Get a list of the playlists owned or followed by the current Spotify user.
+    /// Private and collaborative playlists are only included with the `playlist-read-private` and `playlist-read-collaborative` scopes.
+    pub async fn current_user_playlists(
+        &self,
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<SimplifiedPlaylistObject>> {
+        self.fetch(self.get("me/playlists").query(&hash_map! {
+            "offset" => offset.to_string(),
+            "limit" => limit.to_string(),
+        }))
+        .await
+    }
+
+    /// Get a list of the playlists owned or followed by a Spotify user.
+    pub async fn user_playlists(
+        &self,
+        user_id: &str,
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<SimplifiedPlaylistObject>> {
+        self.fetch(
+            self.get(&format!("users/{}/playlists", user_id))
+                .query(&hash_map! {
+                    "offset" => offset.to_string(),
+                    "limit" => limit.to_string(),
+                }),
+        )
+        .await
+    }
+
+    /// Get a playlist owned by a Spotify user, along with the first page of its items.
+    pub async fn playlist(&self, id: &str) -> Result<PlaylistObject> {
+        self.fetch(
+            self.get(&format!("playlists/{}", id))
+                .query(&hash_map! { "additional_types" => ADDITIONAL_TYPES }),
+        )
+        .await
+    }
+
+    /// Get full details of the items of a playlist owned by a Spotify user.
+    pub async fn playlist_items(
+        &self,
+        id: &str,
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<PlaylistTrackObject>> {
+        self.fetch(
+            self.get(&format!("playlists/{}/tracks", id))
+                .query(&hash_map! {
+                    "offset" => offset.to_string(),
+                    "limit" => limit.to_string(),
+                    "additional_types" => ADDITIONAL_TYPES.to_owned(),
+                }),
+        )
+        .await
+    }
+
+    /// Create a playlist for a Spotify user, which will be empty until items are added.
+    /// The name of `details` is replaced with `name`.
+    pub async fn create_playlist(
+        &self,
+        user_id: &str,
+        name: &str,
+        details: PlaylistDetails,
+    ) -> Result<PlaylistObject> {
+        // spelled out so the right scope is required, public being the default
+        let details = PlaylistDetails {
+            public: details.public.or(Some(true)),
+            ..details.name(name)
+        };
+        self.require_any(details.modify_scopes()).await?;
+        self.fetch(
+            self.post(&format!("users/{}/playlists", user_id))
+                .json(&details),
+        )
+        .await
+    }
+
+    /// Change a playlist’s name and public/private state. (The user must, of course, own the playlist.)
+    pub async fn change_playlist_details(&self, id: &str, details: &PlaylistDetails) -> Result<()> {
+        self.require_any(details.modify_scopes()).await?;
+        self.execute(self.put(&format!("playlists/{}", id)).json(details))
+            .await
+    }
+
+    /// Add one or more items to a user’s playlist, at `position` (zero-based) or appended if `None`.
+    /// They are sent 100 at a time, so the returned `snapshot_id` is that of the last request.
+    pub async fn add_items_to_playlist(
+        &self,
+        id: &str,
+        uris: &[&str],
+        position: Option<usize>,
+    ) -> Result<String> {
+        self.require_any(&MODIFY_SCOPES).await?;
+        let mut snapshot_id = None;
+        for (i, uris) in uris.chunks(MAX_ITEMS).enumerate() {
+            let position = position.map(|position| position + i * MAX_ITEMS);
+            let snapshot: Snapshot = self
+                .fetch(
+                    self.post(&format!("playlists/{}/tracks", id))
+                        .json(&AddItems { uris, position }),
+                )
+                .await?;
+            snapshot_id = Some(snapshot.snapshot_id);
+        }
+        match snapshot_id {
+            Some(snapshot_id) => Ok(snapshot_id),
+            None => self.playlist_snapshot(id).await,
+        }
+    }
+
+    /// Remove one or more items from a user’s playlist.
+    /// Positions are only meaningful with the `snapshot_id` of the version they were taken from,
+    /// which is sent along with every batch of 100 items.
+    pub async fn remove_playlist_items(
+        &self,
+        id: &str,
+        items: &[RemovedItem],
+        snapshot_id: Option<&str>,
+    ) -> Result<String> {
+        self.require_any(&MODIFY_SCOPES).await?;
+        let mut latest = None;
+        for tracks in items.chunks(MAX_ITEMS) {
+            let snapshot: Snapshot = self
+                .fetch(
+                    self.delete(&format!("playlists/{}/tracks", id))
+                        .json(&RemoveItems {
+                            tracks,
+                            snapshot_id,
+                        }),
+                )
+                .await?;
+            latest = Some(snapshot.snapshot_id);
+        }
+        match latest {
+            Some(snapshot_id) => Ok(snapshot_id),
+            None => self.playlist_snapshot(id).await,
+        }
+    }
+
+    /// Move the `range_length` items starting at `range_start` to before the item at `insert_before`,
+    /// all zero-based positions in the version of the playlist with `snapshot_id` (or the latest one).
+    pub async fn reorder_playlist_items(
+        &self,
+        id: &str,
+        range_start: usize,
+        range_length: usize,
+        insert_before: usize,
+        snapshot_id: Option<&str>,
+    ) -> Result<String> {
+        self.require_any(&MODIFY_SCOPES).await?;
+        let snapshot: Snapshot = self
+            .fetch(
+                self.put(&format!("playlists/{}/tracks", id))
+                    .json(&ReorderItems {
+                        range_start,
+                        range_length,
+                        insert_before,
+                        snapshot_id,
+                    }),
+            )
+            .await?;
+        Ok(snapshot.snapshot_id)
+    }
+
+    /// Replace all the items in a playlist, clearing it if `uris` is empty.
+    /// Only 100 items can be replaced at once, so the rest are added afterwards.
+    pub async fn replace_playlist_items(&self, id: &str, uris: &[&str]) -> Result<String> {
+        self.require_any(&MODIFY_SCOPES).await?;
+        let (first, rest) = uris.split_at(uris.len().min(MAX_ITEMS));
+        let snapshot: Snapshot = self
+            .fetch(
+                self.put(&format!("playlists/{}/tracks", id))
+                    .json(&hash_map! { "uris" => first }),
+            )
+            .await?;
+        if rest.is_empty() {
+            Ok(snapshot.snapshot_id)
+        } else {
+            self.add_items_to_playlist(id, rest, None).await
+        }
+    }
+
+    /// Replace the image used to represent a specific playlist with a JPEG of at most 256 KB.
+    pub async fn upload_playlist_cover_image(&self, id: &str, jpeg: &[u8]) -> Result<()> {
+        self.require(Scope::UgcImageUpload).await?;
+        self.require_any(&MODIFY_SCOPES).await?;
+        self.execute(
+            self.put(&format!("playlists/{}/images", id))
+                .header(CONTENT_TYPE, "image/jpeg")
+                .body(base64::encode(jpeg)),
+        )
+        .await
+    }
+
+    /// The current `snapshot_id` of a playlist, for when there was nothing to change.
+    async fn playlist_snapshot(&self, id: &str) -> Result<String> {
+        let snapshot: Snapshot = self
+            .fetch(
+                self.get(&format!("playlists/{}", id))
+                    .query(&hash_map! { "fields" => "snapshot_id" }),
+            )
+            .await?;
+        Ok(snapshot.snapshot_id)
+    }
+}
+
+/// What the endpoints changing a playlist's items respond with.
+#[derive(Debug, Deserialize)]
+struct Snapshot {
+    snapshot_id: String,
+}
+
+#[derive(Debug, Serialize)]
+struct AddItems<'a> {
+    uris: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct RemoveItems<'a> {
+    tracks: &'a [RemovedItem],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snapshot_id: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReorderItems<'a> {
+    range_start: usize,
+    range_length: usize,
+    insert_before: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snapshot_id: Option<&'a str>,
+}
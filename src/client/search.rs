@@ -0,0 +1,217 @@
+use std::{borrow::Cow, fmt, ops::RangeInclusive};
+
+use common_macros::hash_map;
+
+use super::SpotifyClient;
+use crate::{error::Result, objects::SearchResults};
+
+/// What to search for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchType {
+    /// Albums, as [`SimplifiedAlbumObject`](crate::objects::SimplifiedAlbumObject)s.
+    Album,
+    /// Artists, as [`ArtistObject`](crate::objects::ArtistObject)s.
+    Artist,
+    /// Playlists, as [`SimplifiedPlaylistObject`](crate::objects::SimplifiedPlaylistObject)s.
+    Playlist,
+    /// Tracks, as [`TrackObject`](crate::objects::TrackObject)s.
+    Track,
+    /// Podcasts, as [`SimplifiedShowObject`](crate::objects::SimplifiedShowObject)s.
+    Show,
+    /// Podcast episodes, as [`EpisodeObject`](crate::objects::EpisodeObject)s.
+    Episode,
+}
+
+impl SearchType {
+    /// Every type there is, to search for everything at once.
+    pub const ALL: [SearchType; 6] = [
+        SearchType::Album,
+        SearchType::Artist,
+        SearchType::Playlist,
+        SearchType::Track,
+        SearchType::Show,
+        SearchType::Episode,
+    ];
+
+    /// The name Spotify knows the type by, e.g. `album`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SearchType::Album => "album",
+            SearchType::Artist => "artist",
+            SearchType::Playlist => "playlist",
+            SearchType::Track => "track",
+            SearchType::Show => "show",
+            SearchType::Episode => "episode",
+        }
+    }
+}
+
+impl fmt::Display for SearchType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A search query: keywords, narrowed down with [field filters](https://developer.spotify.com/documentation/web-api/reference/#endpoint-search).
+/// It is written the way Spotify expects it, e.g. `blue artist:"Miles Davis" year:1950-1959`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    keywords: Option<String>,
+    filters: Vec<(&'static str, String)>,
+}
+
+impl SearchQuery {
+    /// A query matching `keywords` anywhere, e.g. in the name of a track or of its artist.
+    /// Use [`SearchQuery::default`] to only search by the filters.
+    pub fn new(keywords: impl Into<String>) -> Self {
+        Self {
+            keywords: Some(keywords.into()),
+            filters: Vec::new(),
+        }
+    }
+
+    /// Only match albums, artists and tracks by this artist.
+    pub fn artist(self, artist: impl Into<String>) -> Self {
+        self.filter("artist", artist.into())
+    }
+
+    /// Only match albums and tracks from this album.
+    pub fn album(self, album: impl Into<String>) -> Self {
+        self.filter("album", album.into())
+    }
+
+    /// Only match tracks with this name.
+    pub fn track(self, track: impl Into<String>) -> Self {
+        self.filter("track", track.into())
+    }
+
+    /// Only match albums, artists and tracks released in `year`.
+    pub fn year(self, year: u16) -> Self {
+        self.filter("year", year.to_string())
+    }
+
+    /// Only match albums, artists and tracks released in `years`, e.g. `1990..=1999`.
+    pub fn years(self, years: RangeInclusive<u16>) -> Self {
+        self.filter("year", format!("{}-{}", years.start(), years.end()))
+    }
+
+    /// Only match artists and tracks of this genre.
+    pub fn genre(self, genre: impl Into<String>) -> Self {
+        self.filter("genre", genre.into())
+    }
+
+    /// Only match the track with this [International Standard Recording Code](https://en.wikipedia.org/wiki/International_Standard_Recording_Code).
+    pub fn isrc(self, isrc: impl Into<String>) -> Self {
+        self.filter("isrc", isrc.into())
+    }
+
+    /// Only match the album with this [Universal Product Code](https://en.wikipedia.org/wiki/Universal_Product_Code).
+    pub fn upc(self, upc: impl Into<String>) -> Self {
+        self.filter("upc", upc.into())
+    }
+
+    /// Only match albums released in the past two weeks.
+    pub fn tag_new(self) -> Self {
+        self.filter("tag", "new".to_owned())
+    }
+
+    /// Only match albums with the lowest 10% popularity.
+    pub fn tag_hipster(self) -> Self {
+        self.filter("tag", "hipster".to_owned())
+    }
+
+    fn filter(mut self, field: &'static str, value: String) -> Self {
+        self.filters.push((field, value));
+        self
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        if let Some(keywords) = &self.keywords {
+            f.write_str(keywords)?;
+            separator = " ";
+        }
+        for (field, value) in &self.filters {
+            write!(f, "{}{}:{}", separator, field, quote(value))?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+/// Quotes values of more than one word, so the filter applies to all of them.
+/// Quotes can't be escaped, so any in the value are dropped.
+fn quote(value: &str) -> Cow<'_, str> {
+    if value.contains(|c: char| c.is_whitespace() || c == '"' || c == ':') {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+impl SpotifyClient {
+    /// Get Spotify catalog information about albums, artists, playlists, tracks, shows or episodes that match `query`.
+    /// Each of the `types` gets its own page of results, all starting at `offset`.
+    pub async fn search(
+        &self,
+        query: &SearchQuery,
+        types: &[SearchType],
+        offset: usize,
+        limit: u8,
+    ) -> Result<SearchResults> {
+        let types: Vec<_> = types.iter().map(|r#type| r#type.as_str()).collect();
+        self.fetch(self.get("search").query(&hash_map! {
+            "q" => query.to_string(),
+            "type" => types.join(","),
+            "offset" => offset.to_string(),
+            "limit" => limit.to_string(),
+        }))
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_keywords_and_filters() {
+        let query = SearchQuery::new("blue").artist("Coltrane").year(1959);
+        assert_eq!(query.to_string(), "blue artist:Coltrane year:1959");
+    }
+
+    #[test]
+    fn quotes_values_with_spaces_or_colons() {
+        let query = SearchQuery::new("blue")
+            .artist("Miles Davis")
+            .album("Live: 1964");
+        assert_eq!(
+            query.to_string(),
+            r#"blue artist:"Miles Davis" album:"Live: 1964""#
+        );
+    }
+
+    #[test]
+    fn drops_quotes_from_values() {
+        let query = SearchQuery::default().track(r#"The "Real" Thing"#);
+        assert_eq!(query.to_string(), r#"track:"The Real Thing""#);
+
+        let query = SearchQuery::default().artist(r#""Weird""#);
+        assert_eq!(query.to_string(), r#"artist:"Weird""#);
+    }
+
+    #[test]
+    fn writes_year_ranges() {
+        let query = SearchQuery::new("grunge").years(1990..=1999);
+        assert_eq!(query.to_string(), "grunge year:1990-1999");
+    }
+
+    #[test]
+    fn writes_filters_only() {
+        let query = SearchQuery::default().genre("jazz").tag_new();
+        assert_eq!(query.to_string(), "genre:jazz tag:new");
+        assert_eq!(SearchQuery::default().to_string(), "");
+    }
+}
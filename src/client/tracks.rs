@@ -0,0 +1,83 @@
+use common_macros::hash_map;
+use futures::Stream;
+
+use super::{id_chunks, without_body, SpotifyClient};
+use crate::{
+    error::Result,
+    objects::{PagingObject, SavedTrackObject, TrackObject},
+    scope::Scope,
+};
+
+/// The most tracks the saved track endpoints take at once.
+const MAX_TRACKS: usize = 50;
+
+impl SpotifyClient {
+    /// Get Spotify catalog information for a single track identified by its unique Spotify ID.
+    pub async fn track(&self, id: &str) -> Result<TrackObject> {
+        self.fetch(self.get(&format!("tracks/{}", id))).await
+    }
+
+    /// Get a list of the songs saved in the current user’s ‘Your Music’ library.
+    pub async fn saved_tracks(
+        &self,
+        offset: usize,
+        limit: u8,
+    ) -> Result<PagingObject<SavedTrackObject>> {
+        self.require(Scope::UserLibraryRead).await?;
+        self.fetch(self.get("me/tracks").query(&hash_map! {
+            "offset" => offset.to_string(),
+            "limit" => limit.to_string(),
+        }))
+        .await
+    }
+
+    /// Save one or more tracks to the current user’s ‘Your Music’ library.
+    pub async fn save_tracks(&self, ids: &[&str]) -> Result<()> {
+        self.require(Scope::UserLibraryModify).await?;
+        for ids in id_chunks(ids, MAX_TRACKS) {
+            self.execute(without_body(
+                self.put("me/tracks").query(&hash_map! { "ids" => ids }),
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Remove one or more tracks from the current user’s ‘Your Music’ library.
+    pub async fn remove_saved_tracks(&self, ids: &[&str]) -> Result<()> {
+        self.require(Scope::UserLibraryModify).await?;
+        for ids in id_chunks(ids, MAX_TRACKS) {
+            self.execute(self.delete("me/tracks").query(&hash_map! { "ids" => ids }))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Check if one or more tracks is already saved in the current Spotify user’s ‘Your Music’ library.
+    /// Each track gets a `bool`, in the same order as the IDs.
+    pub async fn check_saved_tracks(&self, ids: &[&str]) -> Result<Vec<bool>> {
+        self.require(Scope::UserLibraryRead).await?;
+        let mut saved = Vec::with_capacity(ids.len());
+        for ids in id_chunks(ids, MAX_TRACKS) {
+            let chunk: Vec<bool> = self
+                .fetch(
+                    self.get("me/tracks/contains")
+                        .query(&hash_map! { "ids" => ids }),
+                )
+                .await?;
+            saved.extend(chunk);
+        }
+        Ok(saved)
+    }
+
+    /// Get all the songs saved in the current user’s ‘Your Music’ library, in order.
+    /// After the first page, up to `concurrency` pages are fetched at once, which is a lot faster for big libraries.
+    pub fn all_saved_tracks(
+        &self,
+        concurrency: usize,
+    ) -> impl Stream<Item = Result<SavedTrackObject>> + '_ {
+        self.fetch_pages(concurrency, move |offset, limit| {
+            self.saved_tracks(offset, limit)
+        })
+    }
+}
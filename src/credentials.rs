@@ -0,0 +1,151 @@
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+/// The environment variable the client id is read from.
+pub const CLIENT_ID_VAR: &str = "SPOTIFRY_CLIENT_ID";
+/// The environment variable the client secret is read from.
+pub const CLIENT_SECRET_VAR: &str = "SPOTIFRY_CLIENT_SECRET";
+
+/// The credentials of a Spotify application, as shown on its page in the
+/// [dashboard](https://developer.spotify.com/dashboard).
+#[derive(Clone)]
+pub struct Credentials {
+    client_id: String,
+    client_secret: Option<String>,
+}
+
+impl Credentials {
+    /// The credentials of the application with the given id and secret.
+    pub fn new(client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: Some(client_secret.into()),
+        }
+    }
+
+    /// The credentials of an application that can't keep a secret, such as a distributed CLI,
+    /// which can only authorize with PKCE.
+    pub fn without_secret(client_id: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: None,
+        }
+    }
+
+    /// Reads the credentials from the `SPOTIFRY_CLIENT_ID` and `SPOTIFRY_CLIENT_SECRET` environment variables.
+    /// Only the id is required, as the secret isn't needed with PKCE.
+    pub fn from_env() -> Result<Self, CredentialsError> {
+        CredentialsFile::default().merge_env().into_credentials()
+    }
+
+    /// Reads the credentials from a TOML file with `client_id` and `client_secret` keys.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CredentialsError> {
+        CredentialsFile::read(path.as_ref())?.into_credentials()
+    }
+
+    /// Reads the credentials from the environment, falling back to the file at [`Credentials::default_path`].
+    /// Each of the two values is looked up separately, so the environment can override just one of them.
+    pub fn load() -> Result<Self, CredentialsError> {
+        let file = match Self::default_path() {
+            Some(path) if path.exists() => CredentialsFile::read(&path)?,
+            _ => CredentialsFile::default(),
+        };
+        file.merge_env().into_credentials()
+    }
+
+    /// Where [`Credentials::load`] looks for a credentials file: `spotifry/credentials.toml` in the user's config directory.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("spotifry").join("credentials.toml"))
+    }
+
+    /// The Client ID of the application.
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// The Client Secret of the application, if it has one.
+    pub fn client_secret(&self) -> Option<&str> {
+        self.client_secret.as_deref()
+    }
+
+    /// The Client Secret, for the flows which can't do without it.
+    pub(crate) fn require_secret(&self) -> Result<&str, CredentialsError> {
+        self.client_secret()
+            .ok_or(CredentialsError::MissingClientSecret)
+    }
+
+    /// The same credentials, without the secret.
+    pub(crate) fn into_public(self) -> Self {
+        Self::without_secret(self.client_id)
+    }
+}
+
+// the secret should not end up in logs
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("client_id", &self.client_id)
+            .finish()
+    }
+}
+
+/// Why the credentials couldn't be loaded.
+#[derive(Debug, Error)]
+pub enum CredentialsError {
+    #[error("No client id, set `SPOTIFRY_CLIENT_ID` or `client_id` in the credentials file")]
+    MissingClientId,
+    #[error(
+        "No client secret, set `SPOTIFRY_CLIENT_SECRET` or `client_secret` in the credentials file, or use PKCE"
+    )]
+    MissingClientSecret,
+    #[error("Couldn't read the credentials file {}", .path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("The credentials file {} is not valid TOML", .path.display())]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+/// The contents of a credentials file, any of which may be missing.
+#[derive(Debug, Default, Deserialize)]
+struct CredentialsFile {
+    client_id: Option<String>,
+    client_secret: Option<String>,
+}
+
+impl CredentialsFile {
+    fn read(path: &Path) -> Result<Self, CredentialsError> {
+        let contents = fs::read_to_string(path).map_err(|source| CredentialsError::Read {
+            path: path.to_owned(),
+            source,
+        })?;
+        toml::from_str(&contents).map_err(|source| CredentialsError::Parse {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    /// Overrides the values with the ones set in the environment.
+    fn merge_env(self) -> Self {
+        Self {
+            client_id: env::var(CLIENT_ID_VAR).ok().or(self.client_id),
+            client_secret: env::var(CLIENT_SECRET_VAR).ok().or(self.client_secret),
+        }
+    }
+
+    fn into_credentials(self) -> Result<Credentials, CredentialsError> {
+        Ok(Credentials {
+            client_id: self
+                .client_id
+                .filter(|id| !id.is_empty())
+                .ok_or(CredentialsError::MissingClientId)?,
+            client_secret: self.client_secret.filter(|secret| !secret.is_empty()),
+        })
+    }
+}
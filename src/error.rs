@@ -0,0 +1,235 @@
+use std::{io, time::Duration};
+
+use reqwest::{header::RETRY_AFTER, Response, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use thiserror::Error;
+
+use crate::{auth::AuthorizationError, credentials::CredentialsError, scope::MissingScope};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Everything that can go wrong when talking to Spotify.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The request couldn't be sent, or the response couldn't be received.
+    #[error("Network error")]
+    Network(#[source] reqwest::Error),
+    /// 401: The access token is invalid or has expired, even after refreshing it.
+    #[error("Unauthorized: {message}")]
+    Unauthorized { message: String },
+    /// 403: Spotify understood the request but refused it,
+    /// e.g. because the user hasn't granted a scope or doesn't have Premium.
+    #[error("Forbidden: {message}")]
+    Forbidden { message: String },
+    /// 404: The requested resource doesn't exist.
+    #[error("Not found: {message}")]
+    NotFound { message: String },
+    /// A player command was sent while none of the user's devices are active.
+    #[error("No active device: {message}")]
+    NoActiveDevice { message: String },
+    /// The player can only be controlled for users with Spotify Premium.
+    #[error("Premium required: {message}")]
+    PremiumRequired { message: String },
+    /// 429: Too many requests were sent, retry after the given time (if Spotify said).
+    #[error("Rate limited, retry after {retry_after:?}")]
+    RateLimited { retry_after: Option<Duration> },
+    /// 5xx: Something went wrong on Spotify's side.
+    #[error("Server error {status}: {message}")]
+    Server { status: StatusCode, message: String },
+    /// Any other unsuccessful status, usually 400 for a malformed request.
+    #[error("Request failed with {status}: {message}")]
+    Api { status: StatusCode, message: String },
+    /// The Accounts service refused to hand out tokens, e.g. `invalid_grant` for an expired refresh token.
+    #[error("The Accounts service failed with `{error}`: {}", .description.as_deref().unwrap_or("no description"))]
+    Accounts {
+        error: String,
+        description: Option<String>,
+    },
+    /// The response wasn't the JSON it should've been.
+    #[error("Couldn't parse the response")]
+    Decode(#[source] serde_json::Error),
+    #[error(transparent)]
+    Credentials(#[from] CredentialsError),
+    #[error(transparent)]
+    Authorization(#[from] AuthorizationError),
+    #[error(transparent)]
+    MissingScope(#[from] MissingScope),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Network(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Decode(e)
+    }
+}
+
+impl Error {
+    /// The error for an unsuccessful response, described by its `body`.
+    fn from_response(status: StatusCode, retry_after: Option<Duration>, body: &str) -> Self {
+        let message = match serde_json::from_str(body) {
+            Ok(ErrorBody::Regular { error }) => match error.reason.as_deref() {
+                Some("NO_ACTIVE_DEVICE") => {
+                    return Error::NoActiveDevice {
+                        message: error.message,
+                    }
+                }
+                Some("PREMIUM_REQUIRED") => {
+                    return Error::PremiumRequired {
+                        message: error.message,
+                    }
+                }
+                _ => error.message,
+            },
+            Ok(ErrorBody::Authentication {
+                error,
+                error_description,
+            }) => {
+                return Error::Accounts {
+                    error,
+                    description: error_description,
+                }
+            }
+            Err(_) => body.to_owned(),
+        };
+        match status {
+            StatusCode::UNAUTHORIZED => Error::Unauthorized { message },
+            StatusCode::FORBIDDEN => Error::Forbidden { message },
+            StatusCode::NOT_FOUND => Error::NotFound { message },
+            StatusCode::TOO_MANY_REQUESTS => Error::RateLimited { retry_after },
+            status if status.is_server_error() => Error::Server { status, message },
+            status => Error::Api { status, message },
+        }
+    }
+}
+
+/// The bodies Spotify sends along with unsuccessful responses.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ErrorBody {
+    /// [Regular error object](https://developer.spotify.com/documentation/web-api/#regular-error-object), returned by the Web API.
+    Regular { error: RegularErrorObject },
+    /// [Authentication error object](https://developer.spotify.com/documentation/web-api/#authentication-error-object), returned by the Accounts service.
+    Authentication {
+        /// A high level description of the error as specified in [RFC 6749 Section 5.2](https://tools.ietf.org/html/rfc6749#section-5.2).
+        error: String,
+        /// A more detailed description of the error as specified in [RFC 6749 Section 4.1.2.1](https://tools.ietf.org/html/rfc6749#section-4.1.2.1).
+        error_description: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct RegularErrorObject {
+    /// The HTTP status code (also returned in the response header).
+    #[allow(dead_code)]
+    status: u16,
+    /// A short description of the cause of the error.
+    message: String,
+    /// Why a player command failed, e.g. `NO_ACTIVE_DEVICE` or `PREMIUM_REQUIRED`.
+    reason: Option<String>,
+}
+
+/// Turns an unsuccessful response into the matching [`Error`].
+pub(crate) async fn error_for_status(response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let retry_after = retry_after(&response);
+    let body = response.text().await?;
+    Err(Error::from_response(status, retry_after, &body))
+}
+
+/// Deserializes a successful response.
+pub(crate) async fn json<T: DeserializeOwned>(response: Response) -> Result<T> {
+    let body = error_for_status(response).await?.bytes().await?;
+    Ok(serde_json::from_slice(&body)?)
+}
+
+/// How long the `Retry-After` header says to wait before trying again.
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regular_errors_keep_their_message() {
+        let body = r#"{"error": {"status": 404, "message": "Non existing id"}}"#;
+        assert!(matches!(
+            Error::from_response(StatusCode::NOT_FOUND, None, body),
+            Error::NotFound { message } if message == "Non existing id"
+        ));
+
+        let body = r#"{"error": {"status": 400, "message": "Invalid limit"}}"#;
+        assert!(matches!(
+            Error::from_response(StatusCode::BAD_REQUEST, None, body),
+            Error::Api { status: StatusCode::BAD_REQUEST, message } if message == "Invalid limit"
+        ));
+    }
+
+    #[test]
+    fn authentication_errors_keep_their_description() {
+        let body = r#"{"error": "invalid_grant", "error_description": "Refresh token revoked"}"#;
+        assert!(matches!(
+            Error::from_response(StatusCode::BAD_REQUEST, None, body),
+            Error::Accounts { error, description: Some(description) }
+                if error == "invalid_grant" && description == "Refresh token revoked"
+        ));
+
+        let body = r#"{"error": "invalid_client"}"#;
+        assert!(matches!(
+            Error::from_response(StatusCode::BAD_REQUEST, None, body),
+            Error::Accounts { error, description: None } if error == "invalid_client"
+        ));
+    }
+
+    #[test]
+    fn player_errors_are_told_apart_by_their_reason() {
+        let body = r#"{"error": {"status": 404, "message": "Player command failed: No active device found", "reason": "NO_ACTIVE_DEVICE"}}"#;
+        assert!(matches!(
+            Error::from_response(StatusCode::NOT_FOUND, None, body),
+            Error::NoActiveDevice { message } if message.ends_with("No active device found")
+        ));
+
+        let body = r#"{"error": {"status": 403, "message": "Player command failed: Premium required", "reason": "PREMIUM_REQUIRED"}}"#;
+        assert!(matches!(
+            Error::from_response(StatusCode::FORBIDDEN, None, body),
+            Error::PremiumRequired { message } if message.ends_with("Premium required")
+        ));
+
+        let body = r#"{"error": {"status": 403, "message": "Player command failed: Restriction violated", "reason": "UNKNOWN"}}"#;
+        assert!(matches!(
+            Error::from_response(StatusCode::FORBIDDEN, None, body),
+            Error::Forbidden { .. }
+        ));
+    }
+
+    #[test]
+    fn other_bodies_are_the_message() {
+        assert!(matches!(
+            Error::from_response(StatusCode::BAD_GATEWAY, None, "<html>Bad Gateway</html>"),
+            Error::Server { status: StatusCode::BAD_GATEWAY, message } if message == "<html>Bad Gateway</html>"
+        ));
+        assert!(matches!(
+            Error::from_response(StatusCode::TOO_MANY_REQUESTS, Some(Duration::from_secs(7)), ""),
+            Error::RateLimited { retry_after: Some(retry_after) } if retry_after == Duration::from_secs(7)
+        ));
+    }
+}
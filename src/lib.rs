@@ -0,0 +1,31 @@
+#![cfg_attr(feature = "nightly", feature(try_blocks))]
+
+//! An **idiomatic** rust client for the Spotify web API!
+
+/// Warns about something that went wrong without stopping the client, like a token cache that can't be written.
+// TODO: replace with log
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        eprintln!($($arg)*)
+    };
+}
+
+mod auth;
+mod cache;
+mod client;
+mod credentials;
+mod error;
+pub mod objects;
+mod retry;
+mod scope;
+mod session;
+
+pub use auth::AuthorizationError;
+pub use client::{
+    AlbumGroup, Playback, PlayedCursor, PlaylistDetails, RemovedItem, RepeatState, SearchQuery,
+    SearchType, SpotifyClient, SpotifyClientBuilder, TimeRange,
+};
+pub use credentials::{Credentials, CredentialsError, CLIENT_ID_VAR, CLIENT_SECRET_VAR};
+pub use error::{Error, Result};
+pub use retry::RetryPolicy;
+pub use scope::{MissingScope, Scope, ScopeSet, UnknownScope};
@@ -1,7 +1,10 @@
 // this is all extremely cyclical so I did not seperate it into modules
 
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{
+    de::{self, DeserializeOwned},
+    Deserialize, Deserializer,
+};
 
 #[derive(Debug, Deserialize)]
 pub struct TrackRestrictionObject {
@@ -9,11 +12,19 @@ pub struct TrackRestrictionObject {
     /// - `market` - The content item is not available in the given market.
     /// - `product` - The content item is not available for the user’s subscription type.
     /// - `explicit` - The content item is explicit and the user’s account is set to not play explicit content.
+    ///
     /// Additional reasons may be added in the future.
     /// **Note**: If you use this field, make sure that your application safely handles unknown values.
     pub(crate) reason: Option<String>,
 }
 
+impl TrackRestrictionObject {
+    /// The reason for the restriction.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct LinkedTrackObject {
     /// Known external URLs for this track.
@@ -28,6 +39,33 @@ pub struct LinkedTrackObject {
     pub(crate) uri: Option<String>,
 }
 
+impl LinkedTrackObject {
+    /// Known external URLs for this track.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the track.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the track.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The object type: “track”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the track.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ExternalIdObject {
     /// [International Article Number](http://en.wikipedia.org/wiki/International_Article_Number_%28EAN%29)
@@ -38,6 +76,23 @@ pub struct ExternalIdObject {
     pub(crate) upc: Option<String>,
 }
 
+impl ExternalIdObject {
+    /// [International Article Number](http://en.wikipedia.org/wiki/International_Article_Number_%28EAN%29)
+    pub fn ean(&self) -> Option<&str> {
+        self.ean.as_deref()
+    }
+
+    /// [International Standard Recording Code](http://en.wikipedia.org/wiki/International_Standard_Recording_Code)
+    pub fn isrc(&self) -> Option<&str> {
+        self.isrc.as_deref()
+    }
+
+    /// [Universal Product Code](http://en.wikipedia.org/wiki/Universal_Product_Code)
+    pub fn upc(&self) -> Option<&str> {
+        self.upc.as_deref()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct FollowersObject {
     /// A link to the Web API endpoint providing full details of the followers; null if not available.
@@ -47,6 +102,18 @@ pub struct FollowersObject {
     pub(crate) total: Option<usize>,
 }
 
+impl FollowersObject {
+    /// A link to the Web API endpoint providing full details of the followers; null if not available.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The total number of followers.
+    pub fn total(&self) -> Option<usize> {
+        self.total
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ArtistObject {
     /// Known external URLs for this artist.
@@ -74,17 +141,77 @@ pub struct ArtistObject {
     pub(crate) uri: Option<String>,
 }
 
+impl ArtistObject {
+    /// Known external URLs for this artist.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// Information about the followers of the artist.
+    pub fn followers(&self) -> Option<&FollowersObject> {
+        self.followers.as_ref()
+    }
+
+    /// A list of the genres the artist is associated with.
+    pub fn genres(&self) -> Option<&[String]> {
+        self.genres.as_deref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the artist.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the artist.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Images of the artist in various sizes, widest first.
+    pub fn images(&self) -> Option<&[ImageObject]> {
+        self.images.as_deref()
+    }
+
+    /// The name of the artist.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The popularity of the artist.
+    pub fn popularity(&self) -> Option<u8> {
+        self.popularity
+    }
+
+    /// The object type: "artist".
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the artist.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AlbumRestrictionObject {
     /// The reason for the restriction. Supported values:
     /// - `market` - The content item is not available in the given market.
     /// - `product` - The content item is not available for the user’s subscription type.
     /// - `explicit` - The content item is explicit and the user’s account is set to not play explicit content.
+    ///
     /// Additional reasons may be added in the future.
     /// **Note**: If you use this field, make sure that your application safely handles unknown values.
     pub(crate) reason: Option<String>,
 }
 
+impl AlbumRestrictionObject {
+    /// The reason for the restriction.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ImageObject {
     /// The image height in pixels. If unknown: `null` or not returned.
@@ -95,12 +222,36 @@ pub struct ImageObject {
     pub(crate) width: Option<usize>,
 }
 
+impl ImageObject {
+    /// The image height in pixels. If unknown: `null` or not returned.
+    pub fn height(&self) -> Option<usize> {
+        self.height
+    }
+
+    /// The source URL of the image.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// The image width in pixels. If unknown: `null` or not returned.
+    pub fn width(&self) -> Option<usize> {
+        self.width
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ExternalUrlObject {
     /// The [Spotify URL](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the object.
     pub(crate) spotify: Option<String>,
 }
 
+impl ExternalUrlObject {
+    /// The [Spotify URL](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the object.
+    pub fn spotify(&self) -> Option<&str> {
+        self.spotify.as_deref()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SimplifiedArtistObject {
     /// Known external URLs for this artist.
@@ -117,6 +268,38 @@ pub struct SimplifiedArtistObject {
     pub(crate) uri: Option<String>,
 }
 
+impl SimplifiedArtistObject {
+    /// Known external URLs for this artist.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the artist.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the artist.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The name of the artist.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The object type: "artist".
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the artist.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SimplifiedAlbumObject {
     /// The field is present when getting an artist’s albums.
@@ -152,6 +335,78 @@ pub struct SimplifiedAlbumObject {
     pub(crate) uri: Option<String>,
 }
 
+impl SimplifiedAlbumObject {
+    /// The field is present when getting an artist’s albums.
+    pub fn album_group(&self) -> Option<&str> {
+        self.album_group.as_deref()
+    }
+
+    /// The type of the album: one of “album”, “single”, or “compilation”.
+    pub fn album_type(&self) -> Option<&str> {
+        self.album_type.as_deref()
+    }
+
+    /// The artists of the album.
+    pub fn artists(&self) -> Option<&[SimplifiedArtistObject]> {
+        self.artists.as_deref()
+    }
+
+    /// The markets in which the album is available: [ISO 3166-1 alpha-2 country codes](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2). Note that an album is considered available in a market when at least 1 of its tracks is available in that market.
+    pub fn available_markets(&self) -> Option<&[String]> {
+        self.available_markets.as_deref()
+    }
+
+    /// Known external URLs for this album.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the album.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the album.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The cover art for the album in various sizes, widest first.
+    pub fn images(&self) -> Option<&[ImageObject]> {
+        self.images.as_deref()
+    }
+
+    /// The name of the album. In case of an album takedown, the value may be an empty string.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The date the album was first released, for example `1981`. Depending on the precision, it might be shown as `1981-12` or `1981-12-15`.
+    pub fn release_date(&self) -> Option<&str> {
+        self.release_date.as_deref()
+    }
+
+    /// The precision with which `release_date` value is known: `year`, `month`, or `day`.
+    pub fn release_date_precision(&self) -> Option<&str> {
+        self.release_date_precision.as_deref()
+    }
+
+    /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-albumrestrictionobject) for more details.
+    pub fn restrictions(&self) -> Option<&AlbumRestrictionObject> {
+        self.restrictions.as_ref()
+    }
+
+    /// The object type: “album”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the album.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct TrackObject {
     /// The album on which the track appears.
@@ -207,6 +462,108 @@ pub struct TrackObject {
     pub(crate) uri: Option<String>,
 }
 
+impl TrackObject {
+    /// The album on which the track appears.
+    pub fn album(&self) -> Option<&SimplifiedAlbumObject> {
+        self.album.as_ref()
+    }
+
+    /// The artists who performed the track.
+    pub fn artists(&self) -> &[ArtistObject] {
+        &self.artists
+    }
+
+    /// A list of the countries in which the track can be played, identified by their [ISO 3166-1 alpha-2](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
+    pub fn available_markets(&self) -> Option<&[String]> {
+        self.available_markets.as_deref()
+    }
+
+    /// The disc number (usually `1` unless the album consists of more than one disc).
+    pub fn disc_number(&self) -> Option<u8> {
+        self.disc_number
+    }
+
+    /// The track length in milliseconds.
+    pub fn duration_ms(&self) -> Option<usize> {
+        self.duration_ms
+    }
+
+    /// Whether or not the track has explicit lyrics (`true` = yes it does; `false` = no it does not OR unknown).
+    pub fn explicit(&self) -> Option<bool> {
+        self.explicit
+    }
+
+    /// Known external IDs for the track.
+    pub fn external_ids(&self) -> Option<&ExternalIdObject> {
+        self.external_ids.as_ref()
+    }
+
+    /// Known external URLs for this track.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the track.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the track.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Whether or not the track is from a local file.
+    pub fn is_local(&self) -> Option<bool> {
+        self.is_local
+    }
+
+    /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied.
+    pub fn is_playable(&self) -> Option<bool> {
+        self.is_playable
+    }
+
+    /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied, and the requested track has been replaced with different track.
+    pub fn linked_from(&self) -> Option<&LinkedTrackObject> {
+        self.linked_from.as_ref()
+    }
+
+    /// The name of the track.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The popularity of the track.
+    pub fn popularity(&self) -> Option<u8> {
+        self.popularity
+    }
+
+    /// A link to a 30 second preview (MP3 format) of the track. Can be `null`.
+    pub fn preview_url(&self) -> Option<&str> {
+        self.preview_url.as_deref()
+    }
+
+    /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-trackrestrictionobject) for more details.
+    pub fn restrictions(&self) -> Option<&TrackRestrictionObject> {
+        self.restrictions.as_ref()
+    }
+
+    /// The number of the track. If an album has several discs, the track number is the number on the specified disc.
+    pub fn track_number(&self) -> Option<u8> {
+        self.track_number
+    }
+
+    /// The object type: “track”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the track.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SavedTrackObject {
     /// The date and time the track was saved.
@@ -218,20 +575,1412 @@ pub struct SavedTrackObject {
     pub(crate) track: TrackObject,
 }
 
+impl SavedTrackObject {
+    /// The date and time the track was saved.
+    pub fn added_at(&self) -> Option<DateTime<Utc>> {
+        self.added_at
+    }
+
+    /// Information about the track.
+    pub fn track(&self) -> &TrackObject {
+        &self.track
+    }
+}
+
 #[derive(Debug, Deserialize)]
-pub struct PagingObject<T> {
-    /// A link to the Web API endpoint returning the full result of the request.
+pub struct SimplifiedTrackObject {
+    /// The artists who performed the track.
+    /// Each artist object includes a link in `href` to more detailed information about the artist.
+    pub(crate) artists: Vec<SimplifiedArtistObject>,
+    /// A list of the countries in which the track can be played, identified by their [ISO 3166-1 alpha-2](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
+    pub(crate) available_markets: Option<Vec<String>>,
+    /// The disc number (usually `1` unless the album consists of more than one disc).
+    pub(crate) disc_number: Option<u8>,
+    /// The track length in milliseconds.
+    pub(crate) duration_ms: Option<usize>,
+    /// Whether or not the track has explicit lyrics (`true` = yes it does; `false` = no it does not OR unknown).
+    pub(crate) explicit: Option<bool>,
+    /// External URLs for this track.
+    pub(crate) external_urls: Option<ExternalUrlObject>,
+    /// A link to the Web API endpoint providing full details of the track.
     pub(crate) href: Option<String>,
-    /// The requested data.
-    pub(crate) items: Vec<T>,
-    /// The maximum number of items in the response (as set in the query or by default).
-    pub(crate) limit: u8,
-    /// URL to the next page of items. (`null` if none).
-    pub(crate) next: Option<String>,
-    /// The offset of the items returned (as set in the query or by default).
-    pub(crate) offset: usize,
-    /// URL to the previous page of items. (`null` if none)
-    pub(crate) previous: Option<String>,
-    /// The total number of items available to return.
-    pub(crate) total: usize,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the track.
+    pub(crate) id: Option<String>,
+    /// Whether or not the track is from a local file.
+    pub(crate) is_local: Option<bool>,
+    /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied.
+    /// If `true`, the track is playable in the given market. Otherwise `false`.
+    pub(crate) is_playable: Option<bool>,
+    /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied and is only part of the response if the track linking, in fact, exists.
+    /// The requested track has been replaced with a different track.
+    /// The track in the `linked_from` object contains information about the originally requested track.
+    pub(crate) linked_from: Option<LinkedTrackObject>,
+    /// The name of the track.
+    pub(crate) name: String,
+    /// A URL to a 30 second preview (MP3 format) of the track.
+    pub(crate) preview_url: Option<String>,
+    /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-trackrestrictionobject) for more details.
+    pub(crate) restrictions: Option<TrackRestrictionObject>,
+    /// The number of the track. If an album has several discs, the track number is the number on the specified disc.
+    pub(crate) track_number: Option<u8>,
+    /// The object type: “track”.
+    pub(crate) r#type: Option<String>,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the track.
+    pub(crate) uri: Option<String>,
+}
+
+impl SimplifiedTrackObject {
+    /// The artists who performed the track.
+    pub fn artists(&self) -> &[SimplifiedArtistObject] {
+        &self.artists
+    }
+
+    /// A list of the countries in which the track can be played, identified by their [ISO 3166-1 alpha-2](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
+    pub fn available_markets(&self) -> Option<&[String]> {
+        self.available_markets.as_deref()
+    }
+
+    /// The disc number (usually `1` unless the album consists of more than one disc).
+    pub fn disc_number(&self) -> Option<u8> {
+        self.disc_number
+    }
+
+    /// The track length in milliseconds.
+    pub fn duration_ms(&self) -> Option<usize> {
+        self.duration_ms
+    }
+
+    /// Whether or not the track has explicit lyrics (`true` = yes it does; `false` = no it does not OR unknown).
+    pub fn explicit(&self) -> Option<bool> {
+        self.explicit
+    }
+
+    /// External URLs for this track.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the track.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the track.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Whether or not the track is from a local file.
+    pub fn is_local(&self) -> Option<bool> {
+        self.is_local
+    }
+
+    /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied.
+    pub fn is_playable(&self) -> Option<bool> {
+        self.is_playable
+    }
+
+    /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied and is only part of the response if the track linking, in fact, exists.
+    pub fn linked_from(&self) -> Option<&LinkedTrackObject> {
+        self.linked_from.as_ref()
+    }
+
+    /// The name of the track.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// A URL to a 30 second preview (MP3 format) of the track.
+    pub fn preview_url(&self) -> Option<&str> {
+        self.preview_url.as_deref()
+    }
+
+    /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-trackrestrictionobject) for more details.
+    pub fn restrictions(&self) -> Option<&TrackRestrictionObject> {
+        self.restrictions.as_ref()
+    }
+
+    /// The number of the track. If an album has several discs, the track number is the number on the specified disc.
+    pub fn track_number(&self) -> Option<u8> {
+        self.track_number
+    }
+
+    /// The object type: “track”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the track.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CopyrightObject {
+    /// The copyright text for this content.
+    pub(crate) text: String,
+    /// The type of copyright: `C` = the copyright, `P` = the sound recording (performance) copyright.
+    pub(crate) r#type: String,
+}
+
+impl CopyrightObject {
+    /// The copyright text for this content.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The type of copyright: `C` = the copyright, `P` = the sound recording (performance) copyright.
+    pub fn r#type(&self) -> &str {
+        &self.r#type
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlbumObject {
+    /// The type of the album: one of “album”, “single”, or “compilation”.
+    pub(crate) album_type: Option<String>,
+    /// The artists of the album.
+    /// Each artist object includes a link in `href` to more detailed information about the artist.
+    pub(crate) artists: Vec<SimplifiedArtistObject>,
+    /// The markets in which the album is available: [ISO 3166-1 alpha-2 country codes](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2). Note that an album is considered available in a market when at least 1 of its tracks is available in that market.
+    pub(crate) available_markets: Option<Vec<String>>,
+    /// The copyright statements of the album.
+    pub(crate) copyrights: Option<Vec<CopyrightObject>>,
+    /// Known external IDs for the album.
+    pub(crate) external_ids: Option<ExternalIdObject>,
+    /// Known external URLs for this album.
+    pub(crate) external_urls: Option<ExternalUrlObject>,
+    /// A list of the genres used to classify the album.
+    /// For example: `"Prog Rock"`, `"Post-Grunge"`. (If not yet classified, the array is empty.)
+    pub(crate) genres: Option<Vec<String>>,
+    /// A link to the Web API endpoint providing full details of the album.
+    pub(crate) href: Option<String>,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the album.
+    pub(crate) id: Option<String>,
+    /// The cover art for the album in various sizes, widest first.
+    pub(crate) images: Option<Vec<ImageObject>>,
+    /// The label for the album.
+    pub(crate) label: Option<String>,
+    /// The name of the album. In case of an album takedown, the value may be an empty string.
+    pub(crate) name: String,
+    /// The popularity of the album.
+    /// The value will be between 0 and 100, with 100 being the most popular.
+    /// The popularity is calculated from the popularity of the album’s individual tracks.
+    pub(crate) popularity: Option<u8>,
+    /// The date the album was first released, for example `1981`. Depending on the precision, it might be shown as `1981-12` or `1981-12-15`.
+    pub(crate) release_date: Option<String>,
+    /// The precision with which `release_date` value is known: `year`, `month`, or `day`.
+    pub(crate) release_date_precision: Option<String>,
+    /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-albumrestrictionobject) for more details.
+    pub(crate) restrictions: Option<AlbumRestrictionObject>,
+    /// The tracks of the album.
+    pub(crate) tracks: PagingObject<SimplifiedTrackObject>,
+    /// The object type: “album”.
+    pub(crate) r#type: Option<String>,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the album.
+    pub(crate) uri: Option<String>,
+}
+
+impl AlbumObject {
+    /// The type of the album: one of “album”, “single”, or “compilation”.
+    pub fn album_type(&self) -> Option<&str> {
+        self.album_type.as_deref()
+    }
+
+    /// The artists of the album.
+    pub fn artists(&self) -> &[SimplifiedArtistObject] {
+        &self.artists
+    }
+
+    /// The markets in which the album is available: [ISO 3166-1 alpha-2 country codes](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2). Note that an album is considered available in a market when at least 1 of its tracks is available in that market.
+    pub fn available_markets(&self) -> Option<&[String]> {
+        self.available_markets.as_deref()
+    }
+
+    /// The copyright statements of the album.
+    pub fn copyrights(&self) -> Option<&[CopyrightObject]> {
+        self.copyrights.as_deref()
+    }
+
+    /// Known external IDs for the album.
+    pub fn external_ids(&self) -> Option<&ExternalIdObject> {
+        self.external_ids.as_ref()
+    }
+
+    /// Known external URLs for this album.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A list of the genres used to classify the album.
+    pub fn genres(&self) -> Option<&[String]> {
+        self.genres.as_deref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the album.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the album.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The cover art for the album in various sizes, widest first.
+    pub fn images(&self) -> Option<&[ImageObject]> {
+        self.images.as_deref()
+    }
+
+    /// The label for the album.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// The name of the album. In case of an album takedown, the value may be an empty string.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The popularity of the album.
+    pub fn popularity(&self) -> Option<u8> {
+        self.popularity
+    }
+
+    /// The date the album was first released, for example `1981`. Depending on the precision, it might be shown as `1981-12` or `1981-12-15`.
+    pub fn release_date(&self) -> Option<&str> {
+        self.release_date.as_deref()
+    }
+
+    /// The precision with which `release_date` value is known: `year`, `month`, or `day`.
+    pub fn release_date_precision(&self) -> Option<&str> {
+        self.release_date_precision.as_deref()
+    }
+
+    /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-albumrestrictionobject) for more details.
+    pub fn restrictions(&self) -> Option<&AlbumRestrictionObject> {
+        self.restrictions.as_ref()
+    }
+
+    /// The tracks of the album.
+    pub fn tracks(&self) -> &PagingObject<SimplifiedTrackObject> {
+        &self.tracks
+    }
+
+    /// The object type: “album”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the album.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SavedAlbumObject {
+    /// The date and time the album was saved.
+    /// Timestamps are returned in ISO 8601 format as Coordinated Universal Time (UTC) with a zero offset: YYYY-MM-DDTHH:MM:SSZ.
+    /// If the time is imprecise (for example, the date/time of an album release), an additional field indicates the precision;
+    /// see for example, release_date in an album object.
+    pub(crate) added_at: Option<DateTime<Utc>>,
+    /// Information about the album.
+    pub(crate) album: AlbumObject,
+}
+
+impl SavedAlbumObject {
+    /// The date and time the album was saved.
+    pub fn added_at(&self) -> Option<DateTime<Utc>> {
+        self.added_at
+    }
+
+    /// Information about the album.
+    pub fn album(&self) -> &AlbumObject {
+        &self.album
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PublicUserObject {
+    /// The name displayed on the user’s profile. `null` if not available.
+    pub(crate) display_name: Option<String>,
+    /// Known public external URLs for this user.
+    pub(crate) external_urls: Option<ExternalUrlObject>,
+    /// Information about the followers of this user.
+    pub(crate) followers: Option<FollowersObject>,
+    /// A link to the Web API endpoint for this user.
+    pub(crate) href: Option<String>,
+    /// The [Spotify user ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for this user.
+    pub(crate) id: String,
+    /// The user’s profile image.
+    pub(crate) images: Option<Vec<ImageObject>>,
+    /// The object type: “user”.
+    pub(crate) r#type: Option<String>,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for this user.
+    pub(crate) uri: Option<String>,
+}
+
+impl PublicUserObject {
+    /// The name displayed on the user’s profile. `null` if not available.
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    /// Known public external URLs for this user.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// Information about the followers of this user.
+    pub fn followers(&self) -> Option<&FollowersObject> {
+        self.followers.as_ref()
+    }
+
+    /// A link to the Web API endpoint for this user.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify user ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for this user.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The user’s profile image.
+    pub fn images(&self) -> Option<&[ImageObject]> {
+        self.images.as_deref()
+    }
+
+    /// The object type: “user”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for this user.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResumePointObject {
+    /// Whether or not the episode has been fully played by the user.
+    pub(crate) fully_played: Option<bool>,
+    /// The user’s most recent position in the episode in milliseconds.
+    pub(crate) resume_position_ms: Option<usize>,
+}
+
+impl ResumePointObject {
+    /// Whether or not the episode has been fully played by the user.
+    pub fn fully_played(&self) -> Option<bool> {
+        self.fully_played
+    }
+
+    /// The user’s most recent position in the episode in milliseconds.
+    pub fn resume_position_ms(&self) -> Option<usize> {
+        self.resume_position_ms
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimplifiedShowObject {
+    /// A list of the countries in which the show can be played, identified by their [ISO 3166-1 alpha-2](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
+    pub(crate) available_markets: Option<Vec<String>>,
+    /// The copyright statements of the show.
+    pub(crate) copyrights: Option<Vec<CopyrightObject>>,
+    /// A description of the show.
+    pub(crate) description: Option<String>,
+    /// Whether or not the show has explicit content (`true` = yes it does; `false` = no it does not OR unknown).
+    pub(crate) explicit: Option<bool>,
+    /// Known external URLs for this show.
+    pub(crate) external_urls: Option<ExternalUrlObject>,
+    /// A link to the Web API endpoint providing full details of the show.
+    pub(crate) href: Option<String>,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the show.
+    pub(crate) id: Option<String>,
+    /// The cover art for the show in various sizes, widest first.
+    pub(crate) images: Option<Vec<ImageObject>>,
+    /// True if all of the show’s episodes are hosted outside of Spotify’s CDN. This field might be `null` in some cases.
+    pub(crate) is_externally_hosted: Option<bool>,
+    /// A list of the languages used in the show, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
+    pub(crate) languages: Option<Vec<String>>,
+    /// The media type of the show.
+    pub(crate) media_type: Option<String>,
+    /// The name of the show.
+    pub(crate) name: String,
+    /// The publisher of the show.
+    pub(crate) publisher: Option<String>,
+    /// The object type: “show”.
+    pub(crate) r#type: Option<String>,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the show.
+    pub(crate) uri: Option<String>,
+}
+
+impl SimplifiedShowObject {
+    /// A list of the countries in which the show can be played, identified by their [ISO 3166-1 alpha-2](http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
+    pub fn available_markets(&self) -> Option<&[String]> {
+        self.available_markets.as_deref()
+    }
+
+    /// The copyright statements of the show.
+    pub fn copyrights(&self) -> Option<&[CopyrightObject]> {
+        self.copyrights.as_deref()
+    }
+
+    /// A description of the show.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Whether or not the show has explicit content (`true` = yes it does; `false` = no it does not OR unknown).
+    pub fn explicit(&self) -> Option<bool> {
+        self.explicit
+    }
+
+    /// Known external URLs for this show.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the show.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the show.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The cover art for the show in various sizes, widest first.
+    pub fn images(&self) -> Option<&[ImageObject]> {
+        self.images.as_deref()
+    }
+
+    /// True if all of the show’s episodes are hosted outside of Spotify’s CDN. This field might be `null` in some cases.
+    pub fn is_externally_hosted(&self) -> Option<bool> {
+        self.is_externally_hosted
+    }
+
+    /// A list of the languages used in the show, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
+    pub fn languages(&self) -> Option<&[String]> {
+        self.languages.as_deref()
+    }
+
+    /// The media type of the show.
+    pub fn media_type(&self) -> Option<&str> {
+        self.media_type.as_deref()
+    }
+
+    /// The name of the show.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The publisher of the show.
+    pub fn publisher(&self) -> Option<&str> {
+        self.publisher.as_deref()
+    }
+
+    /// The object type: “show”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the show.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EpisodeObject {
+    /// A URL to a 30 second preview (MP3 format) of the episode. `null` if not available.
+    pub(crate) audio_preview_url: Option<String>,
+    /// A description of the episode.
+    pub(crate) description: Option<String>,
+    /// The episode length in milliseconds.
+    pub(crate) duration_ms: Option<usize>,
+    /// Whether or not the episode has explicit content (`true` = yes it does; `false` = no it does not OR unknown).
+    pub(crate) explicit: Option<bool>,
+    /// External URLs for this episode.
+    pub(crate) external_urls: Option<ExternalUrlObject>,
+    /// A link to the Web API endpoint providing full details of the episode.
+    pub(crate) href: Option<String>,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the episode.
+    pub(crate) id: Option<String>,
+    /// The cover art for the episode in various sizes, widest first.
+    pub(crate) images: Option<Vec<ImageObject>>,
+    /// True if the episode is hosted outside of Spotify’s CDN.
+    pub(crate) is_externally_hosted: Option<bool>,
+    /// True if the episode is playable in the given market. Otherwise false.
+    pub(crate) is_playable: Option<bool>,
+    /// A list of the languages used in the episode, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
+    pub(crate) languages: Option<Vec<String>>,
+    /// The name of the episode.
+    pub(crate) name: String,
+    /// The date the episode was first released, for example `"1981-12-15"`. Depending on the precision, it might be shown as `"1981"` or `"1981-12"`.
+    pub(crate) release_date: Option<String>,
+    /// The precision with which `release_date` value is known: `"year"`, `"month"`, or `"day"`.
+    pub(crate) release_date_precision: Option<String>,
+    /// The user’s most recent position in the episode. Set if the supplied access token is a user token and has the scope `user-read-playback-position`.
+    pub(crate) resume_point: Option<ResumePointObject>,
+    /// The show on which the episode belongs (missing when the episode is part of a show's own listing).
+    pub(crate) show: Option<SimplifiedShowObject>,
+    /// The object type: “episode”.
+    pub(crate) r#type: Option<String>,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the episode.
+    pub(crate) uri: Option<String>,
+}
+
+impl EpisodeObject {
+    /// A URL to a 30 second preview (MP3 format) of the episode. `null` if not available.
+    pub fn audio_preview_url(&self) -> Option<&str> {
+        self.audio_preview_url.as_deref()
+    }
+
+    /// A description of the episode.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// The episode length in milliseconds.
+    pub fn duration_ms(&self) -> Option<usize> {
+        self.duration_ms
+    }
+
+    /// Whether or not the episode has explicit content (`true` = yes it does; `false` = no it does not OR unknown).
+    pub fn explicit(&self) -> Option<bool> {
+        self.explicit
+    }
+
+    /// External URLs for this episode.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the episode.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the episode.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The cover art for the episode in various sizes, widest first.
+    pub fn images(&self) -> Option<&[ImageObject]> {
+        self.images.as_deref()
+    }
+
+    /// True if the episode is hosted outside of Spotify’s CDN.
+    pub fn is_externally_hosted(&self) -> Option<bool> {
+        self.is_externally_hosted
+    }
+
+    /// True if the episode is playable in the given market. Otherwise false.
+    pub fn is_playable(&self) -> Option<bool> {
+        self.is_playable
+    }
+
+    /// A list of the languages used in the episode, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
+    pub fn languages(&self) -> Option<&[String]> {
+        self.languages.as_deref()
+    }
+
+    /// The name of the episode.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The date the episode was first released, for example `"1981-12-15"`. Depending on the precision, it might be shown as `"1981"` or `"1981-12"`.
+    pub fn release_date(&self) -> Option<&str> {
+        self.release_date.as_deref()
+    }
+
+    /// The precision with which `release_date` value is known: `"year"`, `"month"`, or `"day"`.
+    pub fn release_date_precision(&self) -> Option<&str> {
+        self.release_date_precision.as_deref()
+    }
+
+    /// The user’s most recent position in the episode. Set if the supplied access token is a user token and has the scope `user-read-playback-position`.
+    pub fn resume_point(&self) -> Option<&ResumePointObject> {
+        self.resume_point.as_ref()
+    }
+
+    /// The show on which the episode belongs (missing when the episode is part of a show's own listing).
+    pub fn show(&self) -> Option<&SimplifiedShowObject> {
+        self.show.as_ref()
+    }
+
+    /// The object type: “episode”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the episode.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlaylistTracksRefObject {
+    /// A link to the Web API endpoint where full details of the playlist’s tracks can be retrieved.
+    pub(crate) href: Option<String>,
+    /// Number of tracks in the playlist.
+    pub(crate) total: usize,
+}
+
+impl PlaylistTracksRefObject {
+    /// A link to the Web API endpoint where full details of the playlist’s tracks can be retrieved.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// Number of tracks in the playlist.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimplifiedPlaylistObject {
+    /// `true` if the owner allows other users to modify the playlist.
+    pub(crate) collaborative: Option<bool>,
+    /// The playlist description. Only returned for modified, verified playlists, otherwise `null`.
+    pub(crate) description: Option<String>,
+    /// Known external URLs for this playlist.
+    pub(crate) external_urls: Option<ExternalUrlObject>,
+    /// A link to the Web API endpoint providing full details of the playlist.
+    pub(crate) href: Option<String>,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the playlist.
+    pub(crate) id: String,
+    /// Images for the playlist. The array may be empty or contain up to three images, widest first.
+    pub(crate) images: Option<Vec<ImageObject>>,
+    /// The name of the playlist.
+    pub(crate) name: String,
+    /// The user who owns the playlist.
+    pub(crate) owner: Option<PublicUserObject>,
+    /// The playlist’s public/private status:
+    /// `true` the playlist is public, `false` the playlist is private, `null` the playlist status is not relevant.
+    pub(crate) public: Option<bool>,
+    /// The version identifier for the current playlist. Can be supplied in other requests to target a specific playlist version.
+    pub(crate) snapshot_id: Option<String>,
+    /// A collection containing a link (`href`) to the Web API endpoint where full details of the playlist’s tracks can be retrieved,
+    /// along with the `total` number of tracks in the playlist.
+    pub(crate) tracks: Option<PlaylistTracksRefObject>,
+    /// The object type: “playlist”.
+    pub(crate) r#type: Option<String>,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the playlist.
+    pub(crate) uri: Option<String>,
+}
+
+impl SimplifiedPlaylistObject {
+    /// `true` if the owner allows other users to modify the playlist.
+    pub fn collaborative(&self) -> Option<bool> {
+        self.collaborative
+    }
+
+    /// The playlist description. Only returned for modified, verified playlists, otherwise `null`.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Known external URLs for this playlist.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the playlist.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the playlist.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Images for the playlist. The array may be empty or contain up to three images, widest first.
+    pub fn images(&self) -> Option<&[ImageObject]> {
+        self.images.as_deref()
+    }
+
+    /// The name of the playlist.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The user who owns the playlist.
+    pub fn owner(&self) -> Option<&PublicUserObject> {
+        self.owner.as_ref()
+    }
+
+    /// Whether the playlist is public, `None` if its status is not relevant.
+    pub fn public(&self) -> Option<bool> {
+        self.public
+    }
+
+    /// The version identifier for the current playlist. Can be supplied in other requests to target a specific playlist version.
+    pub fn snapshot_id(&self) -> Option<&str> {
+        self.snapshot_id.as_deref()
+    }
+
+    /// A link to where full details of the playlist’s tracks can be retrieved, along with how many there are.
+    pub fn tracks(&self) -> Option<&PlaylistTracksRefObject> {
+        self.tracks.as_ref()
+    }
+
+    /// The object type: “playlist”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the playlist.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlaylistObject {
+    /// `true` if the owner allows other users to modify the playlist.
+    pub(crate) collaborative: Option<bool>,
+    /// The playlist description. Only returned for modified, verified playlists, otherwise `null`.
+    pub(crate) description: Option<String>,
+    /// Known external URLs for this playlist.
+    pub(crate) external_urls: Option<ExternalUrlObject>,
+    /// Information about the followers of the playlist.
+    pub(crate) followers: Option<FollowersObject>,
+    /// A link to the Web API endpoint providing full details of the playlist.
+    pub(crate) href: Option<String>,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the playlist.
+    pub(crate) id: String,
+    /// Images for the playlist. The array may be empty or contain up to three images, widest first.
+    pub(crate) images: Option<Vec<ImageObject>>,
+    /// The name of the playlist.
+    pub(crate) name: String,
+    /// The user who owns the playlist.
+    pub(crate) owner: Option<PublicUserObject>,
+    /// The playlist’s public/private status:
+    /// `true` the playlist is public, `false` the playlist is private, `null` the playlist status is not relevant.
+    pub(crate) public: Option<bool>,
+    /// The version identifier for the current playlist. Can be supplied in other requests to target a specific playlist version.
+    pub(crate) snapshot_id: Option<String>,
+    /// Information about the tracks of the playlist.
+    pub(crate) tracks: PagingObject<PlaylistTrackObject>,
+    /// The object type: “playlist”.
+    pub(crate) r#type: Option<String>,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the playlist.
+    pub(crate) uri: Option<String>,
+}
+
+impl PlaylistObject {
+    /// `true` if the owner allows other users to modify the playlist.
+    pub fn collaborative(&self) -> Option<bool> {
+        self.collaborative
+    }
+
+    /// The playlist description. Only returned for modified, verified playlists, otherwise `null`.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Known external URLs for this playlist.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// Information about the followers of the playlist.
+    pub fn followers(&self) -> Option<&FollowersObject> {
+        self.followers.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the playlist.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the playlist.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Images for the playlist. The array may be empty or contain up to three images, widest first.
+    pub fn images(&self) -> Option<&[ImageObject]> {
+        self.images.as_deref()
+    }
+
+    /// The name of the playlist.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The user who owns the playlist.
+    pub fn owner(&self) -> Option<&PublicUserObject> {
+        self.owner.as_ref()
+    }
+
+    /// Whether the playlist is public, `None` if its status is not relevant.
+    pub fn public(&self) -> Option<bool> {
+        self.public
+    }
+
+    /// The version identifier for the current playlist. Can be supplied in other requests to target a specific playlist version.
+    pub fn snapshot_id(&self) -> Option<&str> {
+        self.snapshot_id.as_deref()
+    }
+
+    /// Information about the tracks of the playlist.
+    pub fn tracks(&self) -> &PagingObject<PlaylistTrackObject> {
+        &self.tracks
+    }
+
+    /// The object type: “playlist”.
+    pub fn r#type(&self) -> Option<&str> {
+        self.r#type.as_deref()
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the playlist.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlaylistTrackObject {
+    /// The date and time the track or episode was added.
+    /// **Note that some very old playlists may return `null` in this field.**
+    pub(crate) added_at: Option<DateTime<Utc>>,
+    /// The Spotify user who added the track or episode.
+    /// **Note that some very old playlists may return `null` in this field.**
+    pub(crate) added_by: Option<PublicUserObject>,
+    /// Whether this track or episode is a [local file](https://developer.spotify.com/web-api/local-files-spotify-playlists/) or not.
+    pub(crate) is_local: Option<bool>,
+    /// Information about the track or episode, `null` if it's no longer available.
+    pub(crate) track: Option<PlaylistItem>,
+}
+
+impl PlaylistTrackObject {
+    /// The date and time the track or episode was added.
+    pub fn added_at(&self) -> Option<DateTime<Utc>> {
+        self.added_at
+    }
+
+    /// The Spotify user who added the track or episode.
+    pub fn added_by(&self) -> Option<&PublicUserObject> {
+        self.added_by.as_ref()
+    }
+
+    /// Whether this track or episode is a [local file](https://developer.spotify.com/web-api/local-files-spotify-playlists/) or not.
+    pub fn is_local(&self) -> Option<bool> {
+        self.is_local
+    }
+
+    /// Information about the track or episode, `null` if it's no longer available.
+    pub fn track(&self) -> Option<&PlaylistItem> {
+        self.track.as_ref()
+    }
+}
+
+/// A track or a podcast episode, as held by a playlist or played by the player.
+#[derive(Debug)]
+pub enum PlaylistItem {
+    /// A track.
+    Track(TrackObject),
+    /// A podcast episode.
+    Episode(EpisodeObject),
+}
+
+// not `#[serde(tag = "type")]`, which would take the `type` field away from the objects
+impl<'de> Deserialize<'de> for PlaylistItem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let item = serde_json::Value::deserialize(deserializer)?;
+        match item.get("type").and_then(serde_json::Value::as_str) {
+            Some("track") => TrackObject::deserialize(item).map(PlaylistItem::Track),
+            Some("episode") => EpisodeObject::deserialize(item).map(PlaylistItem::Episode),
+            Some(other) => return Err(de::Error::unknown_variant(other, &["track", "episode"])),
+            None => return Err(de::Error::missing_field("type")),
+        }
+        .map_err(de::Error::custom)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PagingObject<T> {
+    /// A link to the Web API endpoint returning the full result of the request.
+    pub(crate) href: Option<String>,
+    /// The requested data.
+    pub(crate) items: Vec<T>,
+    /// The maximum number of items in the response (as set in the query or by default).
+    pub(crate) limit: u8,
+    /// URL to the next page of items. (`null` if none).
+    pub(crate) next: Option<String>,
+    /// The offset of the items returned (as set in the query or by default).
+    pub(crate) offset: usize,
+    /// URL to the previous page of items. (`null` if none)
+    pub(crate) previous: Option<String>,
+    /// The total number of items available to return.
+    pub(crate) total: usize,
+}
+
+impl<T> PagingObject<T> {
+    /// A link to the Web API endpoint returning the full result of the request.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The requested data.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// The maximum number of items in the response (as set in the query or by default).
+    pub fn limit(&self) -> u8 {
+        self.limit
+    }
+
+    /// URL to the next page of items. (`null` if none).
+    pub fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    /// The offset of the items returned (as set in the query or by default).
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// URL to the previous page of items. (`null` if none)
+    pub fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    /// The total number of items available to return.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Takes ownership of the requested data.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeviceObject {
+    /// The device ID. This may be `null`.
+    pub(crate) id: Option<String>,
+    /// If this device is the currently active device.
+    pub(crate) is_active: bool,
+    /// If this device is currently in a private session.
+    pub(crate) is_private_session: bool,
+    /// Whether controlling this device is restricted. At present if this is “true” then no Web API commands will be accepted by this device.
+    pub(crate) is_restricted: bool,
+    /// The name of the device.
+    pub(crate) name: String,
+    /// Device type, such as “computer”, “smartphone” or “speaker”.
+    pub(crate) r#type: String,
+    /// The current volume in percent. This may be `null`.
+    pub(crate) volume_percent: Option<u8>,
+}
+
+impl DeviceObject {
+    /// The device ID. This may be `null`.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// If this device is the currently active device.
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    /// If this device is currently in a private session.
+    pub fn is_private_session(&self) -> bool {
+        self.is_private_session
+    }
+
+    /// Whether controlling this device is restricted. At present if this is “true” then no Web API commands will be accepted by this device.
+    pub fn is_restricted(&self) -> bool {
+        self.is_restricted
+    }
+
+    /// The name of the device.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Device type, such as “computer”, “smartphone” or “speaker”.
+    pub fn r#type(&self) -> &str {
+        &self.r#type
+    }
+
+    /// The current volume in percent. This may be `null`.
+    pub fn volume_percent(&self) -> Option<u8> {
+        self.volume_percent
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ContextObject {
+    /// External URLs for this context.
+    pub(crate) external_urls: Option<ExternalUrlObject>,
+    /// A link to the Web API endpoint providing full details of the track.
+    pub(crate) href: Option<String>,
+    /// The object type, e.g. “artist”, “playlist”, “album”, “show”.
+    pub(crate) r#type: String,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the context.
+    pub(crate) uri: String,
+}
+
+impl ContextObject {
+    /// External URLs for this context.
+    pub fn external_urls(&self) -> Option<&ExternalUrlObject> {
+        self.external_urls.as_ref()
+    }
+
+    /// A link to the Web API endpoint providing full details of the track.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The object type, e.g. “artist”, “playlist”, “album”, “show”.
+    pub fn r#type(&self) -> &str {
+        &self.r#type
+    }
+
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the context.
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CurrentlyPlayingContextObject {
+    /// The device that is currently active.
+    pub(crate) device: DeviceObject,
+    /// The repeat mode: `off`, `track` or `context`.
+    pub(crate) repeat_state: String,
+    /// If shuffle is on or off.
+    pub(crate) shuffle_state: bool,
+    /// A Context Object. Can be `null`.
+    pub(crate) context: Option<ContextObject>,
+    /// Unix Millisecond Timestamp when data was fetched.
+    pub(crate) timestamp: u64,
+    /// Progress into the currently playing track or episode. Can be `null`.
+    pub(crate) progress_ms: Option<usize>,
+    /// If something is currently playing, return `true`.
+    pub(crate) is_playing: bool,
+    /// The currently playing track or episode. Can be `null`.
+    pub(crate) item: Option<PlaylistItem>,
+    /// The object type of the currently playing item. Can be one of `track`, `episode`, `ad` or `unknown`.
+    pub(crate) currently_playing_type: String,
+}
+
+impl CurrentlyPlayingContextObject {
+    /// The device that is currently active.
+    pub fn device(&self) -> &DeviceObject {
+        &self.device
+    }
+
+    /// The repeat mode: `off`, `track` or `context`.
+    pub fn repeat_state(&self) -> &str {
+        &self.repeat_state
+    }
+
+    /// If shuffle is on or off.
+    pub fn shuffle_state(&self) -> bool {
+        self.shuffle_state
+    }
+
+    /// A Context Object. Can be `null`.
+    pub fn context(&self) -> Option<&ContextObject> {
+        self.context.as_ref()
+    }
+
+    /// Unix Millisecond Timestamp when data was fetched.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Progress into the currently playing track or episode. Can be `null`.
+    pub fn progress_ms(&self) -> Option<usize> {
+        self.progress_ms
+    }
+
+    /// If something is currently playing, return `true`.
+    pub fn is_playing(&self) -> bool {
+        self.is_playing
+    }
+
+    /// The currently playing track or episode. Can be `null`.
+    pub fn item(&self) -> Option<&PlaylistItem> {
+        self.item.as_ref()
+    }
+
+    /// The object type of the currently playing item. Can be one of `track`, `episode`, `ad` or `unknown`.
+    pub fn currently_playing_type(&self) -> &str {
+        &self.currently_playing_type
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlayHistoryObject {
+    /// The track the user listened to.
+    pub(crate) track: TrackObject,
+    /// The date and time the track was played.
+    pub(crate) played_at: DateTime<Utc>,
+    /// The context the track was played from.
+    pub(crate) context: Option<ContextObject>,
+}
+
+impl PlayHistoryObject {
+    /// The track the user listened to.
+    pub fn track(&self) -> &TrackObject {
+        &self.track
+    }
+
+    /// The date and time the track was played.
+    pub fn played_at(&self) -> DateTime<Utc> {
+        self.played_at
+    }
+
+    /// The context the track was played from.
+    pub fn context(&self) -> Option<&ContextObject> {
+        self.context.as_ref()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QueueObject {
+    /// The currently playing track or episode. Can be `null`.
+    pub(crate) currently_playing: Option<PlaylistItem>,
+    /// The tracks or episodes in the queue. Can be empty.
+    pub(crate) queue: Vec<PlaylistItem>,
+}
+
+impl QueueObject {
+    /// The currently playing track or episode. Can be `null`.
+    pub fn currently_playing(&self) -> Option<&PlaylistItem> {
+        self.currently_playing.as_ref()
+    }
+
+    /// The tracks or episodes in the queue. Can be empty.
+    pub fn queue(&self) -> &[PlaylistItem] {
+        &self.queue
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchResults {
+    /// The tracks matching the query, if they were searched for.
+    pub(crate) tracks: Option<PagingObject<TrackObject>>,
+    /// The artists matching the query, if they were searched for.
+    pub(crate) artists: Option<PagingObject<ArtistObject>>,
+    /// The albums matching the query, if they were searched for.
+    pub(crate) albums: Option<PagingObject<SimplifiedAlbumObject>>,
+    /// The playlists matching the query, if they were searched for.
+    pub(crate) playlists: Option<PagingObject<SimplifiedPlaylistObject>>,
+    /// The shows matching the query, if they were searched for.
+    pub(crate) shows: Option<PagingObject<SimplifiedShowObject>>,
+    /// The episodes matching the query, if they were searched for.
+    pub(crate) episodes: Option<PagingObject<EpisodeObject>>,
+}
+
+impl SearchResults {
+    /// The tracks matching the query, if they were searched for.
+    pub fn tracks(&self) -> Option<&PagingObject<TrackObject>> {
+        self.tracks.as_ref()
+    }
+
+    /// The artists matching the query, if they were searched for.
+    pub fn artists(&self) -> Option<&PagingObject<ArtistObject>> {
+        self.artists.as_ref()
+    }
+
+    /// The albums matching the query, if they were searched for.
+    pub fn albums(&self) -> Option<&PagingObject<SimplifiedAlbumObject>> {
+        self.albums.as_ref()
+    }
+
+    /// The playlists matching the query, if they were searched for.
+    pub fn playlists(&self) -> Option<&PagingObject<SimplifiedPlaylistObject>> {
+        self.playlists.as_ref()
+    }
+
+    /// The shows matching the query, if they were searched for.
+    pub fn shows(&self) -> Option<&PagingObject<SimplifiedShowObject>> {
+        self.shows.as_ref()
+    }
+
+    /// The episodes matching the query, if they were searched for.
+    pub fn episodes(&self) -> Option<&PagingObject<EpisodeObject>> {
+        self.episodes.as_ref()
+    }
+
+    /// Takes ownership of the tracks, e.g. to page through them.
+    pub fn into_tracks(self) -> Option<PagingObject<TrackObject>> {
+        self.tracks
+    }
+
+    /// Takes ownership of the artists, e.g. to page through them.
+    pub fn into_artists(self) -> Option<PagingObject<ArtistObject>> {
+        self.artists
+    }
+
+    /// Takes ownership of the albums, e.g. to page through them.
+    pub fn into_albums(self) -> Option<PagingObject<SimplifiedAlbumObject>> {
+        self.albums
+    }
+
+    /// Takes ownership of the playlists, e.g. to page through them.
+    pub fn into_playlists(self) -> Option<PagingObject<SimplifiedPlaylistObject>> {
+        self.playlists
+    }
+
+    /// Takes ownership of the shows, e.g. to page through them.
+    pub fn into_shows(self) -> Option<PagingObject<SimplifiedShowObject>> {
+        self.shows
+    }
+
+    /// Takes ownership of the episodes, e.g. to page through them.
+    pub fn into_episodes(self) -> Option<PagingObject<EpisodeObject>> {
+        self.episodes
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CursorPagingObject<T> {
+    /// A link to the Web API endpoint returning the full result of the request.
+    pub(crate) href: Option<String>,
+    /// The requested data.
+    pub(crate) items: Vec<T>,
+    /// The maximum number of items in the response (as set in the query or by default).
+    pub(crate) limit: u8,
+    /// URL to the next page of items. (`null` if none).
+    pub(crate) next: Option<String>,
+    /// The cursors used to find the next set of items.
+    pub(crate) cursors: Option<CursorObject>,
+    /// The total number of items available to return.
+    pub(crate) total: Option<usize>,
+}
+
+impl<T> CursorPagingObject<T> {
+    /// A link to the Web API endpoint returning the full result of the request.
+    pub fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    /// The requested data.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// The maximum number of items in the response (as set in the query or by default).
+    pub fn limit(&self) -> u8 {
+        self.limit
+    }
+
+    /// URL to the next page of items. (`null` if none).
+    pub fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    /// The cursors used to find the next set of items.
+    pub fn cursors(&self) -> Option<&CursorObject> {
+        self.cursors.as_ref()
+    }
+
+    /// The total number of items available to return.
+    pub fn total(&self) -> Option<usize> {
+        self.total
+    }
+
+    /// Takes ownership of the requested data.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CursorObject {
+    /// The cursor to use as key to find the next page of items.
+    pub(crate) after: Option<String>,
+    /// The cursor to use as key to find the previous page of items.
+    pub(crate) before: Option<String>,
+}
+
+impl CursorObject {
+    /// The cursor to use as key to find the next page of items.
+    pub fn after(&self) -> Option<&str> {
+        self.after.as_deref()
+    }
+
+    /// The cursor to use as key to find the previous page of items.
+    pub fn before(&self) -> Option<&str> {
+        self.before.as_deref()
+    }
+}
+
+/// A page of a paged endpoint, which [`SpotifyClient::paginate`](crate::SpotifyClient::paginate) can follow to the end.
+pub trait Page: DeserializeOwned {
+    type Item;
+
+    /// URL to the next page of items, if there is one.
+    fn next_page(&self) -> Option<&str>;
+
+    /// Takes ownership of the requested data.
+    fn into_page_items(self) -> Vec<Self::Item>;
+}
+
+impl<T: DeserializeOwned> Page for PagingObject<T> {
+    type Item = T;
+
+    fn next_page(&self) -> Option<&str> {
+        self.next()
+    }
+
+    fn into_page_items(self) -> Vec<T> {
+        self.into_items()
+    }
+}
+
+impl<T: DeserializeOwned> Page for CursorPagingObject<T> {
+    type Item = T;
+
+    fn next_page(&self) -> Option<&str> {
+        self.next()
+    }
+
+    fn into_page_items(self) -> Vec<T> {
+        self.into_items()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn playlist_items_keep_their_type() {
+        let track: PlaylistItem =
+            serde_json::from_str(r#"{"type": "track", "name": "So What", "artists": []}"#).unwrap();
+        match track {
+            PlaylistItem::Track(track) => assert_eq!(track.r#type(), Some("track")),
+            PlaylistItem::Episode(_) => panic!("expected a track"),
+        }
+
+        let episode: PlaylistItem =
+            serde_json::from_str(r#"{"type": "episode", "name": "Pilot"}"#).unwrap();
+        match episode {
+            PlaylistItem::Episode(episode) => assert_eq!(episode.r#type(), Some("episode")),
+            PlaylistItem::Track(_) => panic!("expected an episode"),
+        }
+
+        assert!(serde_json::from_str::<PlaylistItem>(r#"{"type": "ad"}"#).is_err());
+    }
 }
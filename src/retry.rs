@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::StatusCode;
+
+/// How requests that failed for a transient reason are retried:
+/// rate limited ones after the `Retry-After` Spotify asks for,
+/// and server errors with jittered exponential backoff.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry, failing with the first error instead.
+    pub fn never() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// How many times a request is retried before its error is returned, `3` by default.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The backoff before the first retry of a server error, doubling with every retry; half a second by default.
+    /// Also used for rate limited responses without a `Retry-After`.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// The longest backoff between retries of server errors, 30 seconds by default.
+    /// A `Retry-After` is always honored, even if it's longer.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// How long to wait before retrying a response with `status`, or `None` if it shouldn't be.
+    /// `retries` is how many times the request has already been retried.
+    pub(crate) fn delay(
+        &self,
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        retries: u32,
+    ) -> Option<Duration> {
+        if retries >= self.max_retries {
+            return None;
+        }
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => {
+                Some(retry_after.unwrap_or_else(|| self.backoff(retries)))
+            }
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => Some(self.backoff(retries)),
+            _ => None,
+        }
+    }
+
+    /// Exponential backoff, jittered between half and all of it so concurrent requests don't retry in lockstep.
+    fn backoff(&self, retries: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(retries))
+            .map_or(self.max_delay, |backoff| backoff.min(self.max_delay));
+        backoff.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn honors_retry_after_past_the_max_delay() {
+        let policy = RetryPolicy::default().max_delay(Duration::from_secs(1));
+        let retry_after = Duration::from_secs(120);
+        assert_eq!(
+            policy.delay(StatusCode::TOO_MANY_REQUESTS, Some(retry_after), 0),
+            Some(retry_after)
+        );
+    }
+
+    #[test]
+    fn caps_the_backoff() {
+        let policy = RetryPolicy::default()
+            .max_retries(100)
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(10));
+        for retries in [0, 3, 4, 10, 40, 99] {
+            let delay = policy
+                .delay(StatusCode::SERVICE_UNAVAILABLE, None, retries)
+                .unwrap();
+            let backoff =
+                Duration::from_secs(2u64.pow(retries.min(4))).min(Duration::from_secs(10));
+            assert!(
+                delay >= backoff / 2 && delay <= backoff,
+                "{:?} after {} retries",
+                delay,
+                retries
+            );
+        }
+    }
+
+    #[test]
+    fn stops_once_retries_run_out() {
+        let policy = RetryPolicy::default().max_retries(2);
+        assert!(policy
+            .delay(StatusCode::TOO_MANY_REQUESTS, None, 1)
+            .is_some());
+        assert_eq!(policy.delay(StatusCode::TOO_MANY_REQUESTS, None, 2), None);
+        assert_eq!(policy.delay(StatusCode::BAD_GATEWAY, None, 2), None);
+        assert_eq!(
+            RetryPolicy::never().delay(StatusCode::INTERNAL_SERVER_ERROR, None, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn only_retries_transient_failures() {
+        let policy = RetryPolicy::default();
+        for status in [
+            StatusCode::BAD_REQUEST,
+            StatusCode::UNAUTHORIZED,
+            StatusCode::NOT_FOUND,
+            StatusCode::NOT_IMPLEMENTED,
+        ] {
+            assert_eq!(policy.delay(status, None, 0), None, "{}", status);
+        }
+    }
+}
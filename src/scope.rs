@@ -0,0 +1,297 @@
+use std::{collections::BTreeSet, fmt, iter::FromIterator, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+/// A [scope](https://developer.spotify.com/documentation/general/guides/authorization/scopes/),
+/// allowing access to a specific set of the user's data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Scope {
+    /// Write access to user-provided images.
+    UgcImageUpload,
+    /// Read access to a user’s player state.
+    UserReadPlaybackState,
+    /// Write access to a user’s playback state.
+    UserModifyPlaybackState,
+    /// Read access to a user’s currently playing content.
+    UserReadCurrentlyPlaying,
+    /// Remote control playback of Spotify. This scope is currently available to Spotify iOS and Android SDKs.
+    AppRemoteControl,
+    /// Control playback of a Spotify track. This scope is currently available to the Web Playback SDK. The user must have a Spotify Premium account.
+    Streaming,
+    /// Read access to user's private playlists.
+    PlaylistReadPrivate,
+    /// Include collaborative playlists when requesting a user's playlists.
+    PlaylistReadCollaborative,
+    /// Write access to a user's private playlists.
+    PlaylistModifyPrivate,
+    /// Write access to a user's public playlists.
+    PlaylistModifyPublic,
+    /// Write/delete access to the list of artists and other users that the user follows.
+    UserFollowModify,
+    /// Read access to the list of artists and other users that the user follows.
+    UserFollowRead,
+    /// Read access to a user’s playback position in a content.
+    UserReadPlaybackPosition,
+    /// Read access to a user's top artists and tracks.
+    UserTopRead,
+    /// Read access to a user’s recently played tracks.
+    UserReadRecentlyPlayed,
+    /// Write/delete access to a user's "Your Music" library.
+    UserLibraryModify,
+    /// Read access to a user's library.
+    UserLibraryRead,
+    /// Read access to user’s email address.
+    UserReadEmail,
+    /// Read access to user’s subscription details (type of user account).
+    UserReadPrivate,
+}
+
+impl Scope {
+    /// Every scope there is.
+    pub const ALL: [Scope; 19] = [
+        Scope::UgcImageUpload,
+        Scope::UserReadPlaybackState,
+        Scope::UserModifyPlaybackState,
+        Scope::UserReadCurrentlyPlaying,
+        Scope::AppRemoteControl,
+        Scope::Streaming,
+        Scope::PlaylistReadPrivate,
+        Scope::PlaylistReadCollaborative,
+        Scope::PlaylistModifyPrivate,
+        Scope::PlaylistModifyPublic,
+        Scope::UserFollowModify,
+        Scope::UserFollowRead,
+        Scope::UserReadPlaybackPosition,
+        Scope::UserTopRead,
+        Scope::UserReadRecentlyPlayed,
+        Scope::UserLibraryModify,
+        Scope::UserLibraryRead,
+        Scope::UserReadEmail,
+        Scope::UserReadPrivate,
+    ];
+
+    /// The name Spotify knows the scope by, e.g. `user-library-read`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Scope::UgcImageUpload => "ugc-image-upload",
+            Scope::UserReadPlaybackState => "user-read-playback-state",
+            Scope::UserModifyPlaybackState => "user-modify-playback-state",
+            Scope::UserReadCurrentlyPlaying => "user-read-currently-playing",
+            Scope::AppRemoteControl => "app-remote-control",
+            Scope::Streaming => "streaming",
+            Scope::PlaylistReadPrivate => "playlist-read-private",
+            Scope::PlaylistReadCollaborative => "playlist-read-collaborative",
+            Scope::PlaylistModifyPrivate => "playlist-modify-private",
+            Scope::PlaylistModifyPublic => "playlist-modify-public",
+            Scope::UserFollowModify => "user-follow-modify",
+            Scope::UserFollowRead => "user-follow-read",
+            Scope::UserReadPlaybackPosition => "user-read-playback-position",
+            Scope::UserTopRead => "user-top-read",
+            Scope::UserReadRecentlyPlayed => "user-read-recently-played",
+            Scope::UserLibraryModify => "user-library-modify",
+            Scope::UserLibraryRead => "user-library-read",
+            Scope::UserReadEmail => "user-read-email",
+            Scope::UserReadPrivate => "user-read-private",
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Scope {
+    type Err = UnknownScope;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scope::ALL
+            .iter()
+            .copied()
+            .find(|scope| scope.as_str() == s)
+            .ok_or_else(|| UnknownScope(s.to_owned()))
+    }
+}
+
+/// A scope name that isn't one of [`Scope::ALL`].
+#[derive(Debug, Error)]
+#[error("Unknown scope `{0}`")]
+pub struct UnknownScope(pub String);
+
+/// An endpoint was called without the user having granted the scope it needs.
+#[derive(Debug, Error)]
+#[error("The `{0}` scope is needed, but it wasn't granted")]
+pub struct MissingScope(pub Scope);
+
+/// A set of scopes, such as the ones to ask the user for, or the ones granted for an access token.
+/// It is written the way Spotify expects it, as a space-separated list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScopeSet(BTreeSet<Scope>);
+
+impl ScopeSet {
+    /// An empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `scope` to the set, returning whether it was missing.
+    pub fn insert(&mut self, scope: Scope) -> bool {
+        self.0.insert(scope)
+    }
+
+    /// Whether `scope` is in the set.
+    pub fn contains(&self, scope: Scope) -> bool {
+        self.0.contains(&scope)
+    }
+
+    /// Whether every scope in `other` is in this set too.
+    pub fn is_superset(&self, other: &ScopeSet) -> bool {
+        self.0.is_superset(&other.0)
+    }
+
+    /// Whether there are no scopes in the set, as with application tokens.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The scopes, in a consistent order.
+    pub fn iter(&self) -> impl Iterator<Item = Scope> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Fails with the scope if it isn't in the set.
+    pub fn require(&self, scope: Scope) -> Result<(), MissingScope> {
+        if self.contains(scope) {
+            Ok(())
+        } else {
+            Err(MissingScope(scope))
+        }
+    }
+
+    /// Fails with the first of `scopes` if none of them are in the set.
+    /// Nothing is required of an empty slice.
+    pub fn require_any(&self, scopes: &[Scope]) -> Result<(), MissingScope> {
+        match scopes.first() {
+            Some(&first) if !scopes.iter().any(|scope| self.contains(*scope)) => {
+                Err(MissingScope(first))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FromIterator<Scope> for ScopeSet {
+    fn from_iter<I: IntoIterator<Item = Scope>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Scope> for ScopeSet {
+    fn extend<I: IntoIterator<Item = Scope>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for ScopeSet {
+    type Item = Scope;
+    type IntoIter = std::collections::btree_set::IntoIter<Scope>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl fmt::Display for ScopeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut scopes = self.iter();
+        if let Some(first) = scopes.next() {
+            write!(f, "{}", first)?;
+            for scope in scopes {
+                write!(f, " {}", scope)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a space-separated list of scopes.
+/// Unknown scopes are skipped rather than failing, since Spotify may grant scopes newer than this crate.
+impl FromStr for ScopeSet {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.split_whitespace()
+            .filter_map(|scope| scope.parse().ok())
+            .collect())
+    }
+}
+
+impl Serialize for ScopeSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScopeSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scopes_skipping_unknown_ones() {
+        let scopes: ScopeSet = "user-library-read unknown-scope".parse().unwrap();
+        assert_eq!(scopes.iter().collect::<Vec<_>>(), [Scope::UserLibraryRead]);
+
+        let scopes: ScopeSet = "  playlist-read-private\tuser-top-read  ".parse().unwrap();
+        assert!(scopes.contains(Scope::PlaylistReadPrivate) && scopes.contains(Scope::UserTopRead));
+        assert!("".parse::<ScopeSet>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn writes_scopes_separated_by_spaces() {
+        let scopes: ScopeSet = [Scope::UserLibraryRead, Scope::UgcImageUpload]
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(scopes.to_string(), "ugc-image-upload user-library-read");
+        assert_eq!(ScopeSet::new().to_string(), "");
+    }
+
+    #[test]
+    fn round_trips_through_serde() {
+        let scopes: ScopeSet = Scope::ALL.iter().copied().collect();
+        let json = serde_json::to_string(&scopes).unwrap();
+        assert_eq!(json, format!("\"{}\"", scopes));
+        assert_eq!(serde_json::from_str::<ScopeSet>(&json).unwrap(), scopes);
+
+        let scopes: ScopeSet =
+            serde_json::from_str(r#""user-follow-read some-new-scope""#).unwrap();
+        assert_eq!(scopes.iter().collect::<Vec<_>>(), [Scope::UserFollowRead]);
+    }
+
+    #[test]
+    fn requires_any_of_the_scopes() {
+        let scopes: ScopeSet = "user-library-read".parse().unwrap();
+        assert!(scopes
+            .require_any(&[Scope::UserLibraryModify, Scope::UserLibraryRead])
+            .is_ok());
+        assert!(matches!(
+            scopes.require_any(&[Scope::PlaylistModifyPublic, Scope::PlaylistModifyPrivate]),
+            Err(MissingScope(Scope::PlaylistModifyPublic))
+        ));
+    }
+
+    #[test]
+    fn requires_nothing_of_no_scopes() {
+        assert!(ScopeSet::new().require_any(&[]).is_ok());
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{auth::Tokens, scope::ScopeSet};
+
+/// How long before the access token expires it is already considered stale,
+/// so a request doesn't race the expiry on its way to Spotify.
+const EXPIRY_MARGIN: i64 = 60;
+
+/// The tokens a client currently acts with.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Session {
+    pub(crate) access_token: String,
+    /// Only users' sessions have a refresh token, an application simply asks for a new access token.
+    pub(crate) refresh_token: Option<String>,
+    /// When `access_token` stops being valid.
+    pub(crate) expires_at: DateTime<Utc>,
+    /// The scopes which have been granted for this `access_token`.
+    pub(crate) scope: ScopeSet,
+}
+
+impl Session {
+    /// A session starting with freshly acquired tokens.
+    pub(crate) fn new(tokens: Tokens) -> Self {
+        Self {
+            expires_at: expiry(&tokens),
+            access_token: tokens.access_token,
+            refresh_token: tokens.refresh_token,
+            scope: tokens.scope,
+        }
+    }
+
+    /// Continues the session with refreshed tokens.
+    /// Spotify doesn't always return a new refresh token, in which case the old one stays valid.
+    pub(crate) fn update(&mut self, tokens: Tokens) {
+        self.expires_at = expiry(&tokens);
+        self.access_token = tokens.access_token;
+        self.scope = tokens.scope;
+        if tokens.refresh_token.is_some() {
+            self.refresh_token = tokens.refresh_token;
+        }
+    }
+
+    /// Whether the access token has expired, or is about to.
+    pub(crate) fn is_stale(&self) -> bool {
+        Utc::now() + Duration::seconds(EXPIRY_MARGIN) >= self.expires_at
+    }
+}
+
+fn expiry(tokens: &Tokens) -> DateTime<Utc> {
+    Utc::now() + Duration::seconds(tokens.expires_in as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(access_token: &str, refresh_token: Option<&str>) -> Tokens {
+        Tokens {
+            access_token: access_token.to_owned(),
+            token_type: "Bearer".to_owned(),
+            scope: ScopeSet::new(),
+            expires_in: 3600,
+            refresh_token: refresh_token.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn keeps_the_refresh_token_unless_a_new_one_is_returned() {
+        let mut session = Session::new(tokens("access-1", Some("refresh-1")));
+        assert!(!session.is_stale());
+
+        session.update(tokens("access-2", None));
+        assert_eq!(session.access_token, "access-2");
+        assert_eq!(session.refresh_token.as_deref(), Some("refresh-1"));
+
+        session.update(tokens("access-3", Some("refresh-2")));
+        assert_eq!(session.access_token, "access-3");
+        assert_eq!(session.refresh_token.as_deref(), Some("refresh-2"));
+    }
+
+    #[test]
+    fn is_stale_shortly_before_expiring() {
+        let mut tokens = tokens("access", None);
+        tokens.expires_in = EXPIRY_MARGIN as usize - 1;
+        assert!(Session::new(tokens).is_stale());
+    }
+}
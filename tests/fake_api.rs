@@ -0,0 +1,508 @@
+//! Runs the client against a fake Accounts service and Web API on the loopback interface.
+
+use std::{
+    fs,
+    net::{Ipv4Addr, SocketAddr},
+    process,
+};
+
+use futures::TryStreamExt;
+use spotifry::{Credentials, Error, Scope, ScopeSet, SpotifyClient};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+};
+
+const TRACK: &str = r#"{
+    "id": "11dFghVXANMlKmJXsNCbNl",
+    "name": "Cut To The Feeling",
+    "artists": [{"id": "6sFIWsNpZYqfjUpaCgueju", "name": "Carly Rae Jepsen", "type": "artist"}],
+    "type": "track"
+}"#;
+
+/// What the fake server received: the request line, `Host` and `Authorization` headers and body.
+#[derive(Debug)]
+struct Received {
+    request_line: String,
+    host: Option<String>,
+    authorization: Option<String>,
+    body: String,
+}
+
+impl Received {
+    /// The path and query the request was for.
+    fn target(&self) -> &str {
+        self.request_line.split_whitespace().nth(1).unwrap_or("")
+    }
+
+    /// The path the request was for, without the query.
+    fn path(&self) -> &str {
+        self.target().split('?').next().unwrap_or("")
+    }
+
+    /// The absolute URL of `target` on the fake server, for links to next pages.
+    fn url(&self, target: &str) -> String {
+        format!("http://{}{}", self.host.as_deref().unwrap(), target)
+    }
+
+    /// The value of the query parameter `key`, which the fake server expects to be plain numbers and IDs.
+    fn query(&self, key: &str) -> Option<&str> {
+        self.target()
+            .split_once('?')?
+            .1
+            .split('&')
+            .find_map(|pair| pair.strip_prefix(key)?.strip_prefix('='))
+    }
+}
+
+/// The status and JSON body the fake server answers a request with.
+type Reply = (&'static str, String);
+
+fn ok(body: impl Into<String>) -> Reply {
+    ("200 OK", body.into())
+}
+
+fn not_found() -> Reply {
+    (
+        "404 Not Found",
+        r#"{"error": {"status": 404, "message": "Not found"}}"#.to_owned(),
+    )
+}
+
+/// A token response granting `access_token` for `expires_in` seconds.
+fn tokens(access_token: &str, expires_in: u64) -> String {
+    format!(
+        r#"{{"access_token": "{}", "token_type": "Bearer", "expires_in": {}}}"#,
+        access_token, expires_in
+    )
+}
+
+/// Answers every request with `reply`, one connection at a time,
+/// reporting every request before answering it, so it's been reported once the client has its response.
+async fn serve(
+    mut reply: impl FnMut(&Received) -> Reply + Send + 'static,
+) -> (SocketAddr, mpsc::UnboundedReceiver<Received>) {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (sender, receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            respond(stream, &mut reply, &sender).await;
+        }
+    });
+    (addr, receiver)
+}
+
+async fn respond(
+    stream: TcpStream,
+    reply: impl FnOnce(&Received) -> Reply,
+    sender: &mpsc::UnboundedSender<Received>,
+) {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await.unwrap();
+    let mut host = None;
+    let mut authorization = None;
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).await.unwrap();
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':').unwrap();
+        match &*name.to_ascii_lowercase() {
+            "host" => host = Some(value.trim().to_owned()),
+            "authorization" => authorization = Some(value.trim().to_owned()),
+            "content-length" => content_length = value.trim().parse().unwrap(),
+            _ => {}
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).await.unwrap();
+
+    let received = Received {
+        request_line: request_line.trim_end().to_owned(),
+        host,
+        authorization,
+        body: String::from_utf8(body).unwrap(),
+    };
+    let (status, body) = reply(&received);
+    // the test might not care about what was received
+    let _ = sender.send(received);
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let mut stream = reader.into_inner();
+    stream.write_all(response.as_bytes()).await.unwrap();
+    stream.shutdown().await.unwrap();
+}
+
+/// A client with application tokens from the fake server at `addr`.
+async fn app_client(addr: SocketAddr) -> SpotifyClient {
+    SpotifyClient::builder()
+        .credentials(Credentials::new("client-id", "client-secret"))
+        .token_endpoint(format!("http://{}/api/token", addr))
+        .api_base(format!("http://{}/v1", addr))
+        .client_credentials()
+        .await
+        .unwrap()
+}
+
+/// A client acting on behalf of a user who granted `scopes`, restored from a token cache so nobody has to authorize.
+/// `name` keeps the cache files of tests running at once apart.
+async fn user_client(addr: SocketAddr, scopes: &[Scope], name: &str) -> SpotifyClient {
+    let token_endpoint = format!("http://{}/api/token", addr);
+    let scopes: ScopeSet = scopes.iter().copied().collect();
+    let path = std::env::temp_dir().join(format!("spotifry-{}-{}.json", name, process::id()));
+    fs::write(
+        &path,
+        format!(
+            r#"{{"client_id": "client-id", "token_endpoint": "{}", "access_token": "user-token", "refresh_token": "refresh-token", "expires_at": "2999-01-01T00:00:00Z", "scope": "{}"}}"#,
+            token_endpoint, scopes
+        ),
+    )
+    .unwrap();
+    let client = SpotifyClient::builder()
+        .credentials(Credentials::new("client-id", "client-secret"))
+        .scopes(scopes)
+        .token_cache(&path)
+        .token_endpoint(token_endpoint)
+        .api_base(format!("http://{}/v1", addr))
+        .authorize()
+        .await
+        .unwrap();
+    fs::remove_file(path).unwrap();
+    client
+}
+
+#[tokio::test]
+async fn fetches_a_track_with_app_tokens() {
+    let (addr, mut received) = serve(|request| match request.path() {
+        "/api/token" => ok(tokens("app-token", 3600)),
+        "/v1/tracks/11dFghVXANMlKmJXsNCbNl" => ok(TRACK),
+        _ => not_found(),
+    })
+    .await;
+
+    let client = app_client(addr).await;
+    let token_request = received.recv().await.unwrap();
+    assert_eq!(token_request.request_line, "POST /api/token HTTP/1.1");
+    assert!(token_request.body.contains("grant_type=client_credentials"));
+
+    let track = client.track("11dFghVXANMlKmJXsNCbNl").await.unwrap();
+    assert_eq!(track.name(), "Cut To The Feeling");
+    assert_eq!(track.id(), Some("11dFghVXANMlKmJXsNCbNl"));
+    let track_request = received.recv().await.unwrap();
+    assert_eq!(
+        track_request.request_line,
+        "GET /v1/tracks/11dFghVXANMlKmJXsNCbNl HTTP/1.1"
+    );
+    assert_eq!(
+        track_request.authorization.as_deref(),
+        Some("Bearer app-token")
+    );
+}
+
+#[tokio::test]
+async fn fails_on_invalid_urls() {
+    let (addr, _received) = serve(|request| match request.path() {
+        "/api/token" => ok(tokens("app-token", 3600)),
+        "/v1/albums/album/tracks" => {
+            ok(r#"{"items": [], "limit": 1, "next": "/v1/albums/album/tracks?offset=1", "offset": 0, "total": 2}"#)
+        }
+        _ => not_found(),
+    })
+    .await;
+
+    let client = SpotifyClient::builder()
+        .credentials(Credentials::new("client-id", "client-secret"))
+        .token_endpoint(format!("http://{}/api/token", addr))
+        .api_base("localhost:1234/v1")
+        .client_credentials()
+        .await
+        .unwrap();
+    assert!(matches!(client.track("x").await, Err(Error::Network(_))));
+
+    // a `next` link that isn't a full URL
+    let client = app_client(addr).await;
+    let page = client.album_tracks("album", 0, 1).await.unwrap();
+    let tracks: Result<Vec<_>, _> = client.paginate(page).try_collect().await;
+    assert!(matches!(tracks, Err(Error::Network(_))));
+}
+
+/// Answers the token endpoint with `token-1`, `token-2` and so on, the first one expiring after `first_expires_in` seconds,
+/// and tracks with a 401 unless `accepted` says the bearer token is fine.
+async fn serve_tokens(
+    first_expires_in: u64,
+    accepted: impl Fn(&str) -> bool + Send + 'static,
+) -> (SocketAddr, mpsc::UnboundedReceiver<Received>) {
+    let mut issued = 0;
+    serve(move |request| match request.path() {
+        "/api/token" => {
+            issued += 1;
+            let expires_in = if issued == 1 { first_expires_in } else { 3600 };
+            ok(tokens(&format!("token-{}", issued), expires_in))
+        }
+        "/v1/tracks/11dFghVXANMlKmJXsNCbNl" => match request.authorization.as_deref() {
+            Some(authorization) if accepted(authorization) => ok(TRACK),
+            _ => (
+                "401 Unauthorized",
+                r#"{"error": {"status": 401, "message": "The access token expired"}}"#.to_owned(),
+            ),
+        },
+        _ => not_found(),
+    })
+    .await
+}
+
+/// The request lines and bearer tokens of everything the fake server has received so far.
+fn drain(received: &mut mpsc::UnboundedReceiver<Received>) -> Vec<(String, Option<String>)> {
+    std::iter::from_fn(|| received.try_recv().ok())
+        .map(|request| (request.request_line, request.authorization))
+        .collect()
+}
+
+fn token_request() -> (String, Option<String>) {
+    ("POST /api/token HTTP/1.1".to_owned(), None)
+}
+
+fn track_request(access_token: &str) -> (String, Option<String>) {
+    (
+        "GET /v1/tracks/11dFghVXANMlKmJXsNCbNl HTTP/1.1".to_owned(),
+        Some(format!("Bearer {}", access_token)),
+    )
+}
+
+#[tokio::test]
+async fn refreshes_stale_tokens_before_sending() {
+    let (addr, mut received) =
+        serve_tokens(0, |authorization| authorization == "Bearer token-2").await;
+
+    let client = app_client(addr).await;
+    client.track("11dFghVXANMlKmJXsNCbNl").await.unwrap();
+    assert_eq!(
+        drain(&mut received),
+        [token_request(), token_request(), track_request("token-2")]
+    );
+}
+
+#[tokio::test]
+async fn refreshes_and_retries_once_when_unauthorized() {
+    let (addr, mut received) =
+        serve_tokens(3600, |authorization| authorization == "Bearer token-2").await;
+
+    let client = app_client(addr).await;
+    client.track("11dFghVXANMlKmJXsNCbNl").await.unwrap();
+    assert_eq!(
+        drain(&mut received),
+        [
+            token_request(),
+            track_request("token-1"),
+            token_request(),
+            track_request("token-2"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn gives_up_when_still_unauthorized_after_refreshing() {
+    let (addr, mut received) = serve_tokens(3600, |_| false).await;
+
+    let client = app_client(addr).await;
+    let error = client.track("11dFghVXANMlKmJXsNCbNl").await.unwrap_err();
+    assert!(
+        matches!(error, Error::Unauthorized { message } if message == "The access token expired")
+    );
+    assert_eq!(
+        drain(&mut received),
+        [
+            token_request(),
+            track_request("token-1"),
+            token_request(),
+            track_request("token-2"),
+        ]
+    );
+}
+
+/// Serves `total` saved tracks named after their position, in pages of at most `max_limit`.
+async fn serve_saved_tracks(
+    total: usize,
+    max_limit: usize,
+) -> (SocketAddr, mpsc::UnboundedReceiver<Received>) {
+    serve(move |request| match request.path() {
+        "/v1/me/tracks" => {
+            let offset: usize = request.query("offset").unwrap().parse().unwrap();
+            let limit: usize = request.query("limit").unwrap().parse().unwrap();
+            let limit = limit.min(max_limit);
+            let items: Vec<_> = (offset..total.min(offset + limit))
+                .map(|position| {
+                    format!(
+                        r#"{{"added_at": null, "track": {{"name": "{}", "artists": []}}}}"#,
+                        position
+                    )
+                })
+                .collect();
+            ok(format!(
+                r#"{{"items": [{}], "limit": {}, "next": null, "offset": {}, "total": {}}}"#,
+                items.join(","),
+                limit,
+                offset,
+                total
+            ))
+        }
+        _ => not_found(),
+    })
+    .await
+}
+
+/// Fetches all saved tracks, returning their names and the offsets and limits of the pages requested.
+async fn all_saved_tracks(total: usize, max_limit: usize) -> (Vec<String>, Vec<(usize, usize)>) {
+    let (addr, mut received) = serve_saved_tracks(total, max_limit).await;
+    let client = user_client(
+        addr,
+        &[Scope::UserLibraryRead],
+        &format!("saved-tracks-{}-{}", total, max_limit),
+    )
+    .await;
+
+    let tracks: Vec<_> = client.all_saved_tracks(3).try_collect().await.unwrap();
+    let names = tracks
+        .iter()
+        .map(|saved| saved.track().name().to_owned())
+        .collect();
+    let mut pages: Vec<_> = std::iter::from_fn(|| received.try_recv().ok())
+        .map(|request| {
+            let offset = request.query("offset").unwrap().parse().unwrap();
+            let limit = request.query("limit").unwrap().parse().unwrap();
+            (offset, limit)
+        })
+        .collect();
+    // the pages after the first are fetched concurrently
+    pages.sort_unstable();
+    (names, pages)
+}
+
+fn names(positions: std::ops::Range<usize>) -> Vec<String> {
+    positions.map(|position| position.to_string()).collect()
+}
+
+#[tokio::test]
+async fn fetches_every_page_once_in_order() {
+    let (tracks, pages) = all_saved_tracks(120, 50).await;
+    assert_eq!(tracks, names(0..120));
+    assert_eq!(pages, [(0, 50), (50, 50), (100, 50)]);
+}
+
+#[tokio::test]
+async fn fetches_pages_as_big_as_the_first() {
+    let (tracks, pages) = all_saved_tracks(45, 20).await;
+    assert_eq!(tracks, names(0..45));
+    assert_eq!(pages, [(0, 50), (20, 20), (40, 20)]);
+}
+
+#[tokio::test]
+async fn fetches_a_single_page_once() {
+    let (tracks, pages) = all_saved_tracks(0, 50).await;
+    assert!(tracks.is_empty());
+    assert_eq!(pages, [(0, 50)]);
+
+    let (tracks, pages) = all_saved_tracks(50, 50).await;
+    assert_eq!(tracks, names(0..50));
+    assert_eq!(pages, [(0, 50)]);
+}
+
+#[tokio::test]
+async fn paginates_through_every_page() {
+    let (addr, mut received) = serve(|request| match request.path() {
+        "/api/token" => ok(tokens("app-token", 3600)),
+        "/v1/albums/album/tracks" => {
+            let offset: usize = request.query("offset").unwrap().parse().unwrap();
+            let total = 5;
+            let items: Vec<_> = (offset..total.min(offset + 2))
+                .map(|position| format!(r#"{{"name": "{}", "artists": []}}"#, position))
+                .collect();
+            let next = if offset + 2 < total {
+                format!(
+                    r#""{}""#,
+                    request.url(&format!(
+                        "/v1/albums/album/tracks?offset={}&limit=2",
+                        offset + 2
+                    ))
+                )
+            } else {
+                "null".to_owned()
+            };
+            ok(format!(
+                r#"{{"items": [{}], "limit": 2, "next": {}, "offset": {}, "total": {}}}"#,
+                items.join(","),
+                next,
+                offset,
+                total
+            ))
+        }
+        _ => not_found(),
+    })
+    .await;
+
+    let client = app_client(addr).await;
+    let page = client.album_tracks("album", 0, 2).await.unwrap();
+    let tracks: Vec<_> = client.paginate(page).try_collect().await.unwrap();
+    let tracks: Vec<_> = tracks.iter().map(|track| track.name()).collect();
+    assert_eq!(tracks, ["0", "1", "2", "3", "4"]);
+
+    let offsets: Vec<_> = std::iter::from_fn(|| received.try_recv().ok())
+        .filter_map(|request| Some(request.query("offset")?.to_owned()))
+        .collect();
+    assert_eq!(offsets, ["0", "2", "4"]);
+}
+
+#[tokio::test]
+async fn paginates_through_wrapped_cursor_pages() {
+    let (addr, mut received) = serve(|request| match request.path() {
+        "/v1/me/following" => {
+            let (ids, after): (&[&str], _) = match request.query("after") {
+                None => (&["a", "b"], Some("b")),
+                Some("b") => (&["c", "d"], Some("d")),
+                Some(_) => (&["e"], None),
+            };
+            let items: Vec<_> = ids
+                .iter()
+                .map(|id| format!(r#"{{"id": "{}", "name": "Artist {}"}}"#, id, id))
+                .collect();
+            let next = match after {
+                Some(after) => format!(
+                    r#""{}""#,
+                    request.url(&format!("/v1/me/following?type=artist&after={}&limit=2", after))
+                ),
+                None => "null".to_owned(),
+            };
+            ok(format!(
+                r#"{{"artists": {{"items": [{}], "limit": 2, "next": {}, "cursors": {{"after": {}}}, "total": 5}}}}"#,
+                items.join(","),
+                next,
+                after.map_or("null".to_owned(), |after| format!(r#""{}""#, after)),
+            ))
+        }
+        _ => not_found(),
+    })
+    .await;
+
+    let client = user_client(addr, &[Scope::UserFollowRead], "followed-artists").await;
+    let page = client.followed_artists(None, 2).await.unwrap();
+    let artists: Vec<_> = client.paginate(page).try_collect().await.unwrap();
+    let artists: Vec<_> = artists.iter().filter_map(|artist| artist.id()).collect();
+    assert_eq!(artists, ["a", "b", "c", "d", "e"]);
+
+    let cursors: Vec<_> = std::iter::from_fn(|| received.try_recv().ok())
+        .map(|request| request.query("after").map(str::to_owned))
+        .collect();
+    assert_eq!(cursors, [None, Some("b".to_owned()), Some("d".to_owned())]);
+}